@@ -0,0 +1,83 @@
+//! Times day 07 on a generated transcript of about 100k entries.
+
+use std::fmt::Write;
+
+use advent2022::{bench, registry};
+
+const ENTRIES: usize = 100_000;
+const MAX_DEPTH: usize = 20;
+const RUNS: usize = 10;
+/// Files average half of this, which keeps the total between the 40M bytes
+/// part B has to get below and the 70M bytes of the disk.
+const MAX_SIZE: u64 = 1_400;
+
+/// Xorshift, the transcript is the same on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % n
+    }
+}
+
+fn list(out: &mut String, rng: &mut Rng, left: &mut usize, depth: usize) {
+    out.push_str("$ ls\n");
+
+    let files = (rng.below(8) as usize).min(*left);
+    *left -= files;
+    for idx in 0..files {
+        let _ = writeln!(out, "{} f{}.dat", 1 + rng.below(MAX_SIZE), idx);
+    }
+
+    let dirs = match depth < MAX_DEPTH {
+        true => (rng.below(5) as usize).min(*left),
+        false => 0,
+    };
+    *left -= dirs;
+    for idx in 0..dirs {
+        let _ = writeln!(out, "dir d{}", idx);
+    }
+
+    for idx in 0..dirs {
+        let _ = writeln!(out, "$ cd d{}", idx);
+        list(out, rng, left, depth + 1);
+        out.push_str("$ cd ..\n");
+    }
+}
+
+fn transcript(entries: usize) -> String {
+    let mut out = String::from("$ cd /\n");
+    let mut rng = Rng(0x2022_0007);
+    let mut left = entries;
+
+    for batch in 0.. {
+        let _ = writeln!(out, "$ cd /batch{}", batch);
+        list(&mut out, &mut rng, &mut left, 0);
+
+        if left == 0 {
+            break;
+        }
+    }
+
+    out
+}
+
+fn main() {
+    let input = transcript(ENTRIES);
+    let day = registry::find(7).expect("day 07 is registered");
+
+    println!("{} entries, {} lines", ENTRIES, input.lines().count());
+    let timing = day
+        .bench(&input, RUNS)
+        .expect("day 07 parses the transcript");
+    for (part, stats) in [("A", &timing.part_a), ("B", &timing.part_b)] {
+        if let Err(err) = stats {
+            panic!("day 07 part {} fails on the transcript: {}", part, err);
+        }
+    }
+
+    print!("{}", bench::table(&[timing]));
+}
@@ -0,0 +1,35 @@
+//! Times every day on its input, `cargo bench -- 15 19` limits the days.
+
+use std::path::Path;
+
+use advent2022::{bench, registry};
+
+const RUNS: usize = 10;
+
+fn main() {
+    let filter: Vec<u8> = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+
+    let inputs = Path::new(env!("CARGO_MANIFEST_DIR")).join("input");
+
+    let timings: Vec<_> = registry::DAYS
+        .iter()
+        .filter(|day| filter.is_empty() || filter.contains(&day.day))
+        .filter_map(|day| {
+            let path = inputs.join(format!("day{:02}.txt", day.day));
+            let input = std::fs::read_to_string(path).ok()?;
+
+            match day.bench(&input, RUNS) {
+                Ok(timing) => Some(timing),
+                Err(err) => {
+                    eprintln!("Day {:02}: {}", day.day, err);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    print!("{}", bench::table(&timings));
+}
@@ -0,0 +1,199 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+
+use crate::{
+    input,
+    parse::ParseError,
+    registry::{Answer, Params, Part},
+};
+
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers keyed by day and part, read from a small TOML subset. A
+/// `params` table overrides puzzle parameters for the inputs next to it:
+///
+/// ```toml
+/// [day01]
+/// a = "66186"
+/// b = "196804"
+///
+/// [day15.params]
+/// row = 10
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: HashMap<(u8, Part), String>,
+    params: HashMap<u8, Vec<(String, String)>>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = HashMap::new();
+        let mut params: HashMap<u8, Vec<_>> = HashMap::new();
+        let mut day = None;
+        let mut in_params = false;
+
+        for (idx, line) in input.lines().enumerate() {
+            let err = |expected, offset| ParseError::new(expected, line, offset).shift_lines(idx);
+            let indent = line.len() - line.trim_start().len();
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = trimmed.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .and_then(|header| header.strip_prefix("day"))
+                    .ok_or_else(|| err("\"[dayNN]\"", indent))?;
+                let (number, table) = match header.strip_suffix(".params") {
+                    Some(number) => (number, true),
+                    None => (header, false),
+                };
+                let value = number
+                    .parse::<u8>()
+                    .map_err(|_| err("\"[dayNN]\"", indent))?;
+
+                day = Some(value);
+                in_params = table;
+                continue;
+            }
+
+            let day = day.ok_or_else(|| err("\"[dayNN]\"", indent))?;
+
+            let (key, value) = trimmed
+                .split_once('=')
+                .ok_or_else(|| err("\"a = ...\" or \"b = ...\"", indent))?;
+
+            let value_offset = indent + key.len() + 1;
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted
+                    .strip_suffix('"')
+                    .ok_or_else(|| err("closing '\"'", line.trim_end().len()))?,
+                None if value.chars().all(|c| c.is_ascii_digit() || c == '-') => value,
+                None => return Err(err("quoted answer", value_offset + 1)),
+            };
+
+            if in_params {
+                let name = key.trim();
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+                    return Err(err("parameter name", indent));
+                }
+
+                params
+                    .entry(day)
+                    .or_default()
+                    .push((name.to_owned(), value.to_owned()));
+                continue;
+            }
+
+            let part = Part::parse(key.trim()).map_err(|_| err("part a or b", indent))?;
+            answers.insert((day, part), value.to_owned());
+        }
+
+        Ok(Self { answers, params })
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let input = input::read_file(path)?;
+        Ok(Self::parse(&input)?)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    /// Parameters to solve `day` with, in the order they are listed.
+    pub fn params(&self, day: u8) -> &Params {
+        self.params
+            .get(&day)
+            .map_or(&[], |params| params.as_slice())
+    }
+
+    pub fn check(&self, day: u8, answer: &Answer) -> Verdict {
+        let expected = match self.get(day, answer.part) {
+            Some(expected) => expected,
+            None => return Verdict::Missing,
+        };
+
+        match &answer.value {
+            Ok(found) if found == expected => Verdict::Pass,
+            Ok(found) => Verdict::Fail(format!("expected {}, found {}", expected, found)),
+            Err(err) => Verdict::Fail(err.to_string()),
+        }
+    }
+}
+
+/// Result of checking an answer against the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+/// `answers.toml` next to the puzzle inputs.
+pub fn answers_path() -> PathBuf {
+    input::input_dir().join(ANSWERS_FILE)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "# comment\n[day05]\na = \"CMZ\"\n\n[day06]\nb = 19\n";
+        let answers = Answers::parse(input).unwrap();
+
+        assert_eq!(answers.get(5, Part::A), Some("CMZ"));
+        assert_eq!(answers.get(5, Part::B), None);
+        assert_eq!(answers.get(6, Part::B), Some("19"));
+        assert_eq!(answers.params(5), []);
+    }
+
+    #[test]
+    fn test_params() {
+        let input = "[day15]\na = 26\n\n[day15.params]\nrow = 10\nlimit = \"20\"\n[day16]\nb = 1";
+        let answers = Answers::parse(input).unwrap();
+
+        assert_eq!(
+            answers.params(15),
+            [("row".into(), "10".into()), ("limit".into(), "20".into())]
+        );
+        assert_eq!(answers.get(16, Part::B), Some("1"));
+        assert_eq!(answers.get(15, Part::A), Some("26"));
+
+        let err = Answers::parse("[day15.params]\nRow = 1").unwrap_err();
+        assert_eq!((err.line(), err.expected()), (2, "parameter name"));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[day05]\na = \"CMZ\"").unwrap();
+        let answer = |part, value: &str| Answer {
+            part,
+            value: Ok(value.into()),
+        };
+
+        assert_eq!(answers.check(5, &answer(Part::A, "CMZ")), Verdict::Pass);
+        assert_eq!(
+            answers.check(5, &answer(Part::A, "MCD")),
+            Verdict::Fail("expected CMZ, found MCD".into())
+        );
+        assert_eq!(answers.check(5, &answer(Part::B, "MCD")), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Answers::parse("[day01]\nc = \"1\"").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(err.expected(), "part a or b");
+    }
+}
@@ -0,0 +1,692 @@
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+use advent2022::{
+    answers::{self, Answers, Verdict},
+    bench::{self, Stats, Timing},
+    day07::{Day07, Kind, Planner, Query, Strategy, DISK, REQUIRED},
+    day10::{self, Crt, Day10},
+    day11::{Day11, Policy},
+    input::{read_day, read_day_input},
+    output::{self, Format, Printer},
+    par,
+    registry::{self, Answer, Day, Part},
+    NotImplemented, Solution,
+};
+
+const USAGE: &str =
+    "Usage: aoc run <day|all> [--part a|b] [--input FILE|-] [--format text|json|tsv]
+       aoc bench <day|all> [--runs N] [--input FILE|-] [--format table|json]
+       aoc verify [day|all] [--answers FILE]
+       aoc fs du|tree [PATH] [--input FILE|-]
+       aoc fs find [PATH] [--type f|d] [--name GLOB] [--min-size N] [--max-size N] [--input FILE|-]
+       aoc fs plan [--disk N] [--required N] [--strategy smallest|fewest|least-bytes] [--input FILE|-]
+       aoc crt [--ppm FILE] [--png FILE] [--scale N] [--replay] [--delay MS] [--input FILE|-]
+       aoc monkeys report|throws|counts [--rounds N] [--policy divide:K|modulo|exact] [--top K]
+                   [--format csv|json] [--input FILE|-]
+Options:
+       --verbose  print debug output of the solvers to stderr";
+
+const DEFAULT_RUNS: usize = 5;
+
+const DEFAULT_SCALE: usize = 8;
+const DEFAULT_DELAY: Duration = Duration::from_millis(20);
+
+const DEFAULT_ROUNDS: u64 = 20;
+const DEFAULT_POLICY: Policy = Policy::Divide(3);
+const DEFAULT_TOP: usize = 2;
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    Fs(FsArgs),
+    Crt(CrtArgs),
+    Monkeys(MonkeyArgs),
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        match args.split_first() {
+            Some((cmd, rest)) if cmd == "run" => RunArgs::parse(rest).map(Command::Run),
+            Some((cmd, rest)) if cmd == "bench" => BenchArgs::parse(rest).map(Command::Bench),
+            Some((cmd, rest)) if cmd == "verify" => VerifyArgs::parse(rest).map(Command::Verify),
+            Some((cmd, rest)) if cmd == "fs" => FsArgs::parse(rest).map(Command::Fs),
+            Some((cmd, rest)) if cmd == "crt" => CrtArgs::parse(rest).map(Command::Crt),
+            Some((cmd, rest)) if cmd == "monkeys" => MonkeyArgs::parse(rest).map(Command::Monkeys),
+            Some((cmd, _)) => bail!("Unknown command: {}\n{}", cmd, USAGE),
+            None => bail!(USAGE),
+        }
+    }
+}
+
+fn parse_day(arg: &str) -> Result<Vec<&'static Day>> {
+    if arg == "all" {
+        return Ok(registry::DAYS.iter().collect());
+    }
+
+    let day = arg
+        .parse::<u8>()
+        .map_err(|_| anyhow!("Invalid day: {}\n{}", arg, USAGE))?;
+
+    Ok(vec![registry::find(day)?])
+}
+
+fn check_input(
+    days: Option<Vec<&'static Day>>,
+    input: &Option<String>,
+) -> Result<Vec<&'static Day>> {
+    let days = days.ok_or_else(|| anyhow!("Missing day\n{}", USAGE))?;
+
+    if input.is_some() && days.len() > 1 {
+        bail!("--input can only be used with a single day");
+    }
+
+    Ok(days)
+}
+
+fn value<'a>(args: &mut impl Iterator<Item = &'a String>, flag: &str) -> Result<&'a String> {
+    args.next()
+        .ok_or_else(|| anyhow!("{} requires a value", flag))
+}
+
+struct RunArgs {
+    days: Vec<&'static Day>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    format: Format,
+}
+
+impl RunArgs {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut days = None;
+        let mut parts = Part::all().to_vec();
+        let mut input = None;
+        let mut format = Format::Text;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => parts = vec![Part::parse(value(&mut args, arg)?)?],
+                "--input" => input = Some(value(&mut args, arg)?.clone()),
+                "--format" => format = Format::parse(value(&mut args, arg)?)?,
+                "--verbose" => output::set_verbose(true),
+                day => days = Some(parse_day(day)?),
+            }
+        }
+
+        let days = check_input(days, &input)?;
+
+        Ok(Self {
+            days,
+            parts,
+            input,
+            format,
+        })
+    }
+}
+
+enum BenchFormat {
+    Table,
+    Json,
+}
+
+struct BenchArgs {
+    days: Vec<&'static Day>,
+    runs: usize,
+    format: BenchFormat,
+    input: Option<String>,
+}
+
+impl BenchArgs {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut days = None;
+        let mut runs = DEFAULT_RUNS;
+        let mut format = BenchFormat::Table;
+        let mut input = None;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--runs" => {
+                    let value = value(&mut args, arg)?;
+                    runs = value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|runs| *runs > 0)
+                        .ok_or_else(|| anyhow!("Invalid number of runs: {}", value))?;
+                }
+                "--format" => {
+                    format = match value(&mut args, arg)?.as_str() {
+                        "table" => BenchFormat::Table,
+                        "json" => BenchFormat::Json,
+                        other => bail!("Invalid format: {}", other),
+                    }
+                }
+                "--input" => input = Some(value(&mut args, arg)?.clone()),
+                "--verbose" => output::set_verbose(true),
+                day => days = Some(parse_day(day)?),
+            }
+        }
+
+        let days = check_input(days, &input)?;
+
+        Ok(Self {
+            days,
+            runs,
+            format,
+            input,
+        })
+    }
+}
+
+struct VerifyArgs {
+    days: Vec<&'static Day>,
+    answers: PathBuf,
+}
+
+impl VerifyArgs {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut days = registry::DAYS.iter().collect();
+        let mut answers = answers::answers_path();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" => answers = PathBuf::from(value(&mut args, arg)?),
+                "--verbose" => output::set_verbose(true),
+                day => days = parse_day(day)?,
+            }
+        }
+
+        Ok(Self { days, answers })
+    }
+}
+
+enum FsCommand {
+    Du,
+    Tree,
+    Find(Query),
+    Plan(Planner, Strategy),
+}
+
+/// Queries on the filesystem of a day 07 transcript.
+struct FsArgs {
+    command: FsCommand,
+    path: String,
+    input: Option<String>,
+}
+
+impl FsArgs {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let (command, args) = args
+            .split_first()
+            .ok_or_else(|| anyhow!("Missing fs command\n{}", USAGE))?;
+
+        let mut query = Query::default();
+        let mut planner = Planner::new(DISK, REQUIRED);
+        let mut strategy = Strategy::Smallest;
+        let mut path = "/".to_owned();
+        let mut input = None;
+        let mut args = args.iter();
+
+        let size = |value: &String| {
+            value
+                .parse::<u64>()
+                .map_err(|_| anyhow!("Invalid size: {}", value))
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--type" => {
+                    query.kind = match value(&mut args, arg)?.as_str() {
+                        "f" => Some(Kind::File),
+                        "d" => Some(Kind::Dir),
+                        other => bail!("Invalid type: {}", other),
+                    }
+                }
+                "--name" => query.name = Some(value(&mut args, arg)?.clone()),
+                "--min-size" => query.min_size = Some(size(value(&mut args, arg)?)?),
+                "--max-size" => query.max_size = Some(size(value(&mut args, arg)?)?),
+                "--disk" => planner.disk = size(value(&mut args, arg)?)?,
+                "--required" => planner.required = size(value(&mut args, arg)?)?,
+                "--strategy" => strategy = Strategy::parse(value(&mut args, arg)?)?,
+                "--input" => input = Some(value(&mut args, arg)?.clone()),
+                "--verbose" => output::set_verbose(true),
+                other => path = other.to_owned(),
+            }
+        }
+
+        let command = match command.as_str() {
+            "du" => FsCommand::Du,
+            "tree" => FsCommand::Tree,
+            "find" => FsCommand::Find(query),
+            "plan" => FsCommand::Plan(planner, strategy),
+            other => bail!("Unknown fs command: {}\n{}", other, USAGE),
+        };
+
+        Ok(Self {
+            command,
+            path,
+            input,
+        })
+    }
+}
+
+fn fs(args: &FsArgs) -> Result<()> {
+    let raw = read_day(Day07::DAY, args.input.as_deref())?;
+    let filesystem = Day07::parse(&raw)?;
+
+    if !matches!(args.command, FsCommand::Tree) {
+        for path in filesystem.pending() {
+            eprintln!(
+                "warning: {} was never listed, its size is a lower bound",
+                path
+            );
+        }
+    }
+
+    match &args.command {
+        FsCommand::Du => {
+            for entry in filesystem.du(&args.path)? {
+                println!("{}\t{}", entry.size, entry.path);
+            }
+        }
+        FsCommand::Tree => println!("{}", filesystem.tree(&args.path)?),
+        FsCommand::Find(query) => {
+            for entry in filesystem.find(&args.path, query)? {
+                println!("{}", entry.path);
+            }
+        }
+        FsCommand::Plan(planner, strategy) => {
+            let plan = planner.plan(&filesystem, *strategy)?;
+            for path in &plan.paths {
+                println!("{}", path);
+            }
+            println!("{} bytes freed", plan.freed);
+        }
+    }
+
+    Ok(())
+}
+
+/// Exports of the day 10 CRT, prints the screen without any.
+struct CrtArgs {
+    ppm: Option<PathBuf>,
+    png: Option<PathBuf>,
+    scale: usize,
+    replay: Option<Duration>,
+    input: Option<String>,
+}
+
+impl CrtArgs {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut ppm = None;
+        let mut png = None;
+        let mut scale = DEFAULT_SCALE;
+        let mut replay = false;
+        let mut delay = DEFAULT_DELAY;
+        let mut input = None;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--ppm" => ppm = Some(PathBuf::from(value(&mut args, arg)?)),
+                "--png" => png = Some(PathBuf::from(value(&mut args, arg)?)),
+                "--scale" => {
+                    let value = value(&mut args, arg)?;
+                    scale = value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|scale| *scale > 0)
+                        .ok_or_else(|| anyhow!("Invalid scale: {}", value))?;
+                }
+                "--replay" => replay = true,
+                "--delay" => {
+                    let value = value(&mut args, arg)?;
+                    delay = value
+                        .parse::<u64>()
+                        .map(Duration::from_millis)
+                        .map_err(|_| anyhow!("Invalid delay: {}", value))?;
+                }
+                "--input" => input = Some(value(&mut args, arg)?.clone()),
+                "--verbose" => output::set_verbose(true),
+                other => bail!("Unknown crt option: {}\n{}", other, USAGE),
+            }
+        }
+
+        Ok(Self {
+            ppm,
+            png,
+            scale,
+            replay: replay.then_some(delay),
+            input,
+        })
+    }
+}
+
+fn crt(args: &CrtArgs) -> Result<()> {
+    let raw = read_day(Day10::DAY, args.input.as_deref())?;
+    let cpu = Day10::parse(&raw)?;
+    let screen = Crt::render(cpu.clone())?;
+
+    if let Some(delay) = args.replay {
+        day10::replay(cpu, std::io::stdout().lock(), delay)?;
+    }
+
+    if let Some(path) = &args.ppm {
+        let file = std::fs::File::create(path)
+            .map_err(|err| anyhow!("can't create {}: {}", path.display(), err))?;
+        screen.write_ppm(std::io::BufWriter::new(file), args.scale)?;
+    }
+
+    if let Some(path) = &args.png {
+        write_png(&screen, path, args.scale)?;
+    }
+
+    if args.replay.is_none() && args.ppm.is_none() && args.png.is_none() {
+        print!("{}", screen);
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "png")]
+fn write_png(screen: &Crt, path: &std::path::Path, scale: usize) -> Result<()> {
+    let file = std::fs::File::create(path)
+        .map_err(|err| anyhow!("can't create {}: {}", path.display(), err))?;
+    screen.write_png(std::io::BufWriter::new(file), scale)
+}
+
+#[cfg(not(feature = "png"))]
+fn write_png(_: &Crt, _: &std::path::Path, _: usize) -> Result<()> {
+    bail!("PNG export needs the png feature")
+}
+
+enum MonkeyCommand {
+    Report,
+    Throws,
+    Counts,
+}
+
+/// Runs the day 11 monkeys and reports on them.
+struct MonkeyArgs {
+    command: MonkeyCommand,
+    rounds: u64,
+    policy: Policy,
+    top: usize,
+    json: bool,
+    input: Option<String>,
+}
+
+impl MonkeyArgs {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let (command, args) = args
+            .split_first()
+            .ok_or_else(|| anyhow!("Missing monkeys command\n{}", USAGE))?;
+
+        let command = match command.as_str() {
+            "report" => MonkeyCommand::Report,
+            "throws" => MonkeyCommand::Throws,
+            "counts" => MonkeyCommand::Counts,
+            other => bail!("Unknown monkeys command: {}\n{}", other, USAGE),
+        };
+
+        let mut rounds = DEFAULT_ROUNDS;
+        let mut policy = DEFAULT_POLICY;
+        let mut top = DEFAULT_TOP;
+        let mut json = false;
+        let mut input = None;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--rounds" => {
+                    let value = value(&mut args, arg)?;
+                    rounds = value
+                        .parse()
+                        .map_err(|_| anyhow!("Invalid number of rounds: {}", value))?;
+                }
+                "--policy" => policy = Policy::parse(value(&mut args, arg)?)?,
+                "--top" => {
+                    let value = value(&mut args, arg)?;
+                    top = value
+                        .parse()
+                        .map_err(|_| anyhow!("Invalid number of monkeys: {}", value))?;
+                }
+                "--format" => {
+                    json = match value(&mut args, arg)?.as_str() {
+                        "csv" => false,
+                        "json" => true,
+                        other => bail!("Invalid format: {}", other),
+                    }
+                }
+                "--input" => input = Some(value(&mut args, arg)?.clone()),
+                "--verbose" => output::set_verbose(true),
+                other => bail!("Unknown monkeys option: {}\n{}", other, USAGE),
+            }
+        }
+
+        Ok(Self {
+            command,
+            rounds,
+            policy,
+            top,
+            json,
+            input,
+        })
+    }
+}
+
+fn monkeys(args: &MonkeyArgs) -> Result<()> {
+    let raw = read_day(Day11::DAY, args.input.as_deref())?;
+    let mut game = Day11::parse(&raw)?.with_policy(args.policy)?;
+    if !matches!(args.command, MonkeyCommand::Report) {
+        game = game.with_trace();
+    }
+
+    game.run_rounds(args.rounds)?;
+
+    match (&args.command, game.trace()) {
+        (MonkeyCommand::Throws, Some(trace)) if args.json => print!("{}", trace.throws_json()),
+        (MonkeyCommand::Throws, Some(trace)) => print!("{}", trace.throws_csv()),
+        (MonkeyCommand::Counts, Some(trace)) if args.json => print!("{}", trace.counts_json()),
+        (MonkeyCommand::Counts, Some(trace)) => print!("{}", trace.counts_csv()),
+        _ => println!("{}", game.report(args.top)?),
+    }
+
+    Ok(())
+}
+
+fn solve_day(day: &Day, parts: &[Part], input: Option<&String>) -> Result<Vec<Answer>> {
+    let raw = read_day(day.day, input.map(String::as_str))?;
+
+    day.solve(&raw, parts)
+        .map_err(|err| anyhow!("can't parse input: {}", err))
+}
+
+fn run(args: &RunArgs) -> Result<bool> {
+    let solved = par::map(&args.days, |day| {
+        solve_day(day, &args.parts, args.input.as_ref())
+    });
+
+    let mut printer = Printer::stdout(args.format);
+
+    for (day, answers) in args.days.iter().zip(solved) {
+        match answers {
+            Ok(answers) => printer.answers(day.day, &answers)?,
+            Err(err) => printer.error(day.day, &err)?,
+        }
+    }
+
+    printer.finish()
+}
+
+fn bench_day(day: &Day, runs: usize, input: Option<&String>) -> Option<Timing> {
+    let raw = match read_day(day.day, input.map(String::as_str)) {
+        Ok(raw) => raw,
+        Err(err) => {
+            eprintln!("Day {:02}: {}", day.day, err);
+            return None;
+        }
+    };
+
+    match day.bench(&raw, runs) {
+        Ok(timing) => Some(timing),
+        Err(err) => {
+            eprintln!("Day {:02}: can't parse input: {}", day.day, err);
+            None
+        }
+    }
+}
+
+fn run_bench(args: &BenchArgs) -> bool {
+    let timings: Vec<_> = args
+        .days
+        .iter()
+        .filter_map(|day| bench_day(day, args.runs, args.input.as_ref()))
+        .collect();
+
+    match args.format {
+        BenchFormat::Table => print!("{}", bench::table(&timings)),
+        BenchFormat::Json => print!("{}", bench::json(&timings)),
+    }
+
+    let failed = timings
+        .iter()
+        .filter(|timing| failed(&timing.part_a) || failed(&timing.part_b))
+        .count();
+
+    timings.len() == args.days.len() && failed == 0
+}
+
+/// A part which is not implemented yet is not a failure.
+fn failed(stats: &Result<Stats>) -> bool {
+    matches!(stats, Err(err) if !err.is::<NotImplemented>())
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+struct Check {
+    day: u8,
+    part: Part,
+    verdict: Verdict,
+    elapsed: Duration,
+}
+
+/// Checks both parts of `day`, a day without input counts as missing.
+fn verify_day(day: &Day, answers: &Answers) -> Result<Vec<Check>> {
+    let raw = read_day_input(day.day)?;
+
+    let checks = Part::all()
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let solved = day.solve_with(&raw, &[part], answers.params(day.day));
+            let elapsed = start.elapsed();
+
+            let verdict = match solved {
+                Ok(solved) => solved
+                    .iter()
+                    .map(|answer| answers.check(day.day, answer))
+                    .next()
+                    .unwrap_or(Verdict::Missing),
+                Err(err) => match answers.get(day.day, part) {
+                    Some(_) => Verdict::Fail(format!("can't parse input: {}", err)),
+                    None => Verdict::Missing,
+                },
+            };
+
+            Check {
+                day: day.day,
+                part,
+                verdict,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Ok(checks)
+}
+
+fn report(check: Check, summary: &mut Summary) {
+    let label = format!("Day {:02} part {}", check.day, check.part);
+
+    match check.verdict {
+        Verdict::Pass => {
+            summary.passed += 1;
+            println!("{}: pass ({:.2?})", label, check.elapsed);
+        }
+        Verdict::Fail(reason) => {
+            summary.failed += 1;
+            println!("{}: FAIL ({:.2?}) {}", label, check.elapsed, reason);
+        }
+        Verdict::Missing => {
+            summary.missing += 1;
+            println!("{}: missing ({:.2?})", label, check.elapsed);
+        }
+    }
+}
+
+fn verify(args: &VerifyArgs) -> Result<bool> {
+    let answers = Answers::read(&args.answers)?;
+    let mut summary = Summary::default();
+
+    let checks = par::map(&args.days, |day| verify_day(day, &answers));
+
+    for (day, checks) in args.days.iter().zip(checks) {
+        match checks {
+            Ok(checks) => checks
+                .into_iter()
+                .for_each(|check| report(check, &mut summary)),
+            Err(err) => {
+                println!("Day {:02}: missing ({})", day.day, err);
+                summary.missing += Part::all().len();
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} missing",
+        summary.passed, summary.failed, summary.missing
+    );
+
+    Ok(summary.failed == 0)
+}
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let ok = match Command::parse(&args)? {
+        Command::Run(args) => run(&args)?,
+        Command::Bench(args) => run_bench(&args),
+        Command::Verify(args) => verify(&args)?,
+        Command::Fs(args) => {
+            fs(&args)?;
+            true
+        }
+        Command::Crt(args) => {
+            crt(&args)?;
+            true
+        }
+        Command::Monkeys(args) => {
+            monkeys(&args)?;
+            true
+        }
+    };
+
+    if !ok {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+use crate::output::json_string;
+
+/// Timings of a single stage over several runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Runs `f` `runs` times, stops at the first error.
+    pub fn measure<T, F>(runs: usize, mut f: F) -> Result<Self>
+    where
+        F: FnMut() -> Result<T>,
+    {
+        let runs = runs.max(1);
+        let mut total = Duration::ZERO;
+        let mut min = Duration::MAX;
+        let mut max = Duration::ZERO;
+
+        for _ in 0..runs {
+            let start = Instant::now();
+            std::hint::black_box(f()?);
+            let elapsed = start.elapsed();
+
+            total += elapsed;
+            min = min.min(elapsed);
+            max = max.max(elapsed);
+        }
+
+        Ok(Self {
+            runs,
+            mean: total / runs as u32,
+            min,
+            max,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Timing {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_a: Result<Stats>,
+    pub part_b: Result<Stats>,
+}
+
+impl Timing {
+    fn stages(&self) -> [(&'static str, Result<&Stats, String>); 3] {
+        [
+            ("parse", Ok(&self.parse)),
+            ("part_a", stage(&self.part_a)),
+            ("part_b", stage(&self.part_b)),
+        ]
+    }
+}
+
+fn stage(stats: &Result<Stats>) -> Result<&Stats, String> {
+    stats.as_ref().map_err(|err| err.to_string())
+}
+
+pub fn table(timings: &[Timing]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<4} {:<7} {:>5} {:>12} {:>12} {:>12}",
+        "Day", "Stage", "Runs", "Mean", "Min", "Max"
+    );
+
+    for timing in timings {
+        for (stage, stats) in timing.stages() {
+            let _ = match stats {
+                Ok(stats) => writeln!(
+                    out,
+                    "{:<4} {:<7} {:>5} {:>12} {:>12} {:>12}",
+                    format!("{:02}", timing.day),
+                    stage,
+                    stats.runs,
+                    format!("{:.2?}", stats.mean),
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.max),
+                ),
+                Err(err) => writeln!(out, "{:02}   {:<7} error: {}", timing.day, stage, err),
+            };
+        }
+    }
+
+    out
+}
+
+/// One object per day, durations in nanoseconds.
+pub fn json(timings: &[Timing]) -> String {
+    let days: Vec<_> = timings
+        .iter()
+        .map(|timing| {
+            let stages: Vec<_> = timing
+                .stages()
+                .iter()
+                .map(|(stage, stats)| match stats {
+                    Ok(stats) => format!(
+                        "\"{}\":{{\"runs\":{},\"mean_ns\":{},\"min_ns\":{},\"max_ns\":{}}}",
+                        stage,
+                        stats.runs,
+                        stats.mean.as_nanos(),
+                        stats.min.as_nanos(),
+                        stats.max.as_nanos()
+                    ),
+                    Err(err) => format!("\"{}\":{{\"error\":{}}}", stage, json_string(err)),
+                })
+                .collect();
+
+            format!("{{\"day\":{},{}}}", timing.day, stages.join(","))
+        })
+        .collect();
+
+    format!("[{}]\n", days.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::bail;
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let stats = Stats::measure(3, || {
+            calls += 1;
+            Ok(calls)
+        })
+        .unwrap();
+
+        assert_eq!((stats.runs, calls), (3, 3));
+        assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+    }
+
+    #[test]
+    fn test_json() {
+        let stats = Stats {
+            runs: 1,
+            mean: Duration::from_nanos(5),
+            min: Duration::from_nanos(5),
+            max: Duration::from_nanos(5),
+        };
+        let failed = Stats::measure(1, || -> Result<()> { bail!("not \"done\"") });
+        let timing = Timing {
+            day: 16,
+            parse: stats,
+            part_a: Ok(stats),
+            part_b: failed,
+        };
+
+        assert_eq!(
+            json(&[timing]),
+            "[{\"day\":16,\
+            \"parse\":{\"runs\":1,\"mean_ns\":5,\"min_ns\":5,\"max_ns\":5},\
+            \"part_a\":{\"runs\":1,\"mean_ns\":5,\"min_ns\":5,\"max_ns\":5},\
+            \"part_b\":{\"error\":\"not \\\"done\\\"\"}}]\n"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+#[derive(Debug)]
+pub struct Elf {
+    food: Vec<i64>,
+}
+
+impl Elf {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let food = parse::lines(input, |line| {
+            line.parse::<i64>()
+                .map_err(|_| ParseError::new("number", line, 0))
+        })?;
+
+        let elf = Self { food };
+        Ok(elf)
+    }
+
+    pub fn total(&self) -> i64 {
+        self.food.iter().sum()
+    }
+}
+
+fn foods(elfs: &[Elf]) -> Vec<i64> {
+    let mut foods = elfs.iter().map(|e| e.total()).collect::<Vec<_>>();
+
+    foods.sort();
+    foods.reverse();
+
+    foods
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf>;
+    type A = i64;
+    type B = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::blocks(input, Elf::parse)?)
+    }
+
+    fn part_a(elfs: &Self::Input) -> Result<i64> {
+        foods(elfs)
+            .first()
+            .cloned()
+            .ok_or_else(|| anyhow!("No elfs found"))
+    }
+
+    fn part_b(elfs: &Self::Input) -> Result<i64> {
+        Ok(foods(elfs).iter().take(3).sum())
+    }
+}
@@ -0,0 +1,242 @@
+use anyhow::Result;
+use std::ops::RangeInclusive;
+
+use crate::{
+    parse::{self, ParseError, Parser},
+    Solution,
+};
+
+#[derive(Debug, Clone)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    pub fn parse(input: char) -> Option<Self> {
+        match input {
+            'A' | 'X' => Some(Move::Rock),
+            'B' | 'Y' => Some(Move::Paper),
+            'C' | 'Z' => Some(Move::Scissors),
+            _ => None,
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Lost,
+    Draw,
+    Won,
+}
+
+impl Outcome {
+    pub fn score(&self) -> i32 {
+        match self {
+            Self::Lost => 0,
+            Self::Draw => 3,
+            Self::Won => 6,
+        }
+    }
+
+    pub fn parse(input: char) -> Option<Self> {
+        match input {
+            'X' => Some(Outcome::Lost),
+            'Y' => Some(Outcome::Draw),
+            'Z' => Some(Outcome::Won),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScoreRound {
+    left: Move,
+    right: Move,
+}
+
+impl ScoreRound {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (left, right) = round(input, Move::parse)?;
+
+        Ok(Self { left, right })
+    }
+
+    pub fn score(&self) -> i32 {
+        self.right.score() + self.outcome().score()
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        match (&self.left, &self.right) {
+            (Move::Rock, Move::Paper) => Outcome::Won,
+            (Move::Rock, Move::Scissors) => Outcome::Lost,
+            (Move::Paper, Move::Rock) => Outcome::Lost,
+            (Move::Paper, Move::Scissors) => Outcome::Won,
+            (Move::Scissors, Move::Rock) => Outcome::Won,
+            (Move::Scissors, Move::Paper) => Outcome::Lost,
+            _ => Outcome::Draw,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GuessRound {
+    left: Move,
+    outcome: Outcome,
+}
+
+/// A round `"<A-C> <X-Z>"`, the second letter is read by `right`.
+fn round<T, F>(input: &str, right: F) -> Result<(Move, T), ParseError>
+where
+    F: Fn(char) -> Option<T>,
+{
+    Parser::parse(input, |p| {
+        let left = letter(p, "A, B or C", 'A'..='C', Move::parse)?;
+        p.literal(" ")?;
+        let right = letter(p, "X, Y or Z", 'X'..='Z', right)?;
+
+        Ok((left, right))
+    })
+}
+
+fn letter<T, F>(
+    p: &mut Parser,
+    expected: &str,
+    range: RangeInclusive<char>,
+    parse: F,
+) -> Result<T, ParseError>
+where
+    F: Fn(char) -> Option<T>,
+{
+    let c = p
+        .peek()
+        .filter(|c| range.contains(c))
+        .ok_or_else(|| p.error(expected))?;
+    let value = parse(c).ok_or_else(|| p.error(expected))?;
+
+    p.literal(&c.to_string())?;
+    Ok(value)
+}
+
+impl GuessRound {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (left, outcome) = round(input, Outcome::parse)?;
+
+        Ok(Self { left, outcome })
+    }
+
+    pub fn score(&self) -> i32 {
+        self.right().score() + self.outcome.score()
+    }
+
+    fn right(&self) -> Move {
+        match (&self.left, &self.outcome) {
+            (Move::Rock, Outcome::Lost) => Move::Scissors,
+            (Move::Rock, Outcome::Won) => Move::Paper,
+            (Move::Paper, Outcome::Lost) => Move::Rock,
+            (Move::Paper, Outcome::Won) => Move::Scissors,
+            (Move::Scissors, Outcome::Lost) => Move::Paper,
+            (Move::Scissors, Outcome::Won) => Move::Rock,
+            _ => self.left.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScoreGame {
+    rounds: Vec<ScoreRound>,
+}
+
+impl ScoreGame {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let rounds = parse::lines(input, ScoreRound::parse)?;
+
+        Ok(Self { rounds })
+    }
+
+    pub fn score(&self) -> i32 {
+        self.rounds.iter().map(|round| round.score()).sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct GuessGame {
+    rounds: Vec<GuessRound>,
+}
+
+impl GuessGame {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let rounds = parse::lines(input, GuessRound::parse)?;
+
+        Ok(Self { rounds })
+    }
+
+    pub fn score(&self) -> i32 {
+        self.rounds.iter().map(|round| round.score()).sum()
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = (ScoreGame, GuessGame);
+    type A = i32;
+    type B = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let score = ScoreGame::parse(input)?;
+        let guess = GuessGame::parse(input)?;
+
+        Ok((score, guess))
+    }
+
+    fn part_a((score, _): &Self::Input) -> Result<i32> {
+        Ok(score.score())
+    }
+
+    fn part_b((_, guess): &Self::Input) -> Result<i32> {
+        Ok(guess.score())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let (score, guess) = Day02::parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!((score.score(), guess.score()), (15, 12));
+
+        for (input, column, expected) in [
+            ("AXB", 2, "' '"),
+            ("A Y trailing", 4, "end of line"),
+            ("X Y", 1, "A, B or C"),
+            ("A B", 3, "X, Y or Z"),
+            ("A", 2, "' '"),
+        ] {
+            let err = ScoreRound::parse(input).unwrap_err();
+            assert_eq!(
+                (err.column(), err.expected()),
+                (column, expected),
+                "{}",
+                input
+            );
+        }
+
+        let err = Day02::parse("A Y\nB  X\n").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line(), err.column()), (2, 3));
+    }
+}
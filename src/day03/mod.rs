@@ -0,0 +1,165 @@
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashSet;
+
+use crate::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub fn value(input: &char) -> Result<i32> {
+    match input {
+        'a'..='z' => Ok(*input as i32 - 'a' as i32 + 1),
+        'A'..='Z' => Ok(*input as i32 - 'A' as i32 + 27),
+        _ => bail!("Invalid common item: {}", input),
+    }
+}
+
+#[derive(Debug)]
+pub struct Rucksack {
+    items: String,
+    size: usize,
+}
+
+impl Rucksack {
+    pub fn parse(items: &str) -> Result<Self, ParseError> {
+        if let Some(pos) = items.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::new("item a-z or A-Z", items, pos));
+        }
+
+        if !items.len().is_multiple_of(2) {
+            let expected = "even number of items";
+            return Err(ParseError::new(expected, items, items.len()));
+        }
+
+        let size = items.len() / 2;
+        let items = items.to_owned();
+        Ok(Self { items, size })
+    }
+
+    pub fn common(&self) -> Result<char> {
+        let left = self.items[0..self.size].chars().collect::<HashSet<_>>();
+        let right = self.items[self.size..].chars().collect::<HashSet<_>>();
+
+        let result = left
+            .intersection(&right)
+            .next()
+            .ok_or_else(|| anyhow!("Common char not found"))?;
+
+        Ok(*result)
+    }
+
+    pub fn items(&self) -> HashSet<char> {
+        self.items.chars().collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Group<'a> {
+    a: &'a Rucksack,
+    b: &'a Rucksack,
+    c: &'a Rucksack,
+}
+
+impl<'a> Group<'a> {
+    pub fn new(input: &'a [Rucksack]) -> Self {
+        let a = &input[0];
+        let b = &input[1];
+        let c = &input[2];
+
+        Self { a, b, c }
+    }
+
+    pub fn common(&self) -> Result<char> {
+        let ab = self
+            .a
+            .items()
+            .intersection(&self.b.items())
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let item = ab
+            .intersection(&self.c.items())
+            .cloned()
+            .next()
+            .ok_or_else(|| anyhow!("Common for group not found"))?;
+
+        Ok(item)
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type A = i32;
+    type B = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input, Rucksack::parse)?)
+    }
+
+    fn part_a(items: &Self::Input) -> Result<i32> {
+        let task_a = items
+            .iter()
+            .map(|item| item.common().and_then(|c| value(&c)))
+            .collect::<Result<Vec<_>>>()?
+            .iter()
+            .sum();
+
+        Ok(task_a)
+    }
+
+    fn part_b(items: &Self::Input) -> Result<i32> {
+        let chunks = items.chunks_exact(3);
+        if !chunks.remainder().is_empty() {
+            bail!(
+                "{} rucksacks do not split into groups of three",
+                items.len()
+            );
+        }
+
+        let groups = chunks.map(Group::new).collect::<Vec<_>>();
+
+        let task_b = groups
+            .iter()
+            .map(|item| item.common().and_then(|c| value(&c)))
+            .collect::<Result<Vec<_>>>()?
+            .iter()
+            .sum();
+
+        Ok(task_b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let rucksack = Rucksack::parse("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+        assert_eq!(rucksack.common().unwrap(), 'p');
+
+        let err = Rucksack::parse("a\u{e9}b").unwrap_err();
+        assert_eq!(err.column(), 2);
+        assert_eq!(err.expected(), "item a-z or A-Z");
+
+        let err = Day03::parse("ab\nabc\n").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line(), err.column()), (2, 4));
+    }
+
+    #[test]
+    fn test_groups() {
+        let group = "vJrwpWtwJgWrhcsFMMfFFhFp\n\
+                     jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n\
+                     PmmdzqPrVvPwwTWBwg\n";
+        let items = Day03::parse(group).unwrap();
+        assert_eq!(Day03::part_b(&items).unwrap(), 18);
+
+        let items = Day03::parse(&format!("{}ab\n", group)).unwrap();
+        assert!(Day03::part_b(&items).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+
+use crate::{
+    parse::{self, ParseError, Parser},
+    Solution,
+};
+
+pub struct Section {
+    a: i32,
+    b: i32,
+}
+
+impl Section {
+    pub fn parse(p: &mut Parser) -> Result<Self, ParseError> {
+        let a = p.int()?;
+        p.literal("-")?;
+        let b = p.int()?;
+
+        Ok(Self { a, b })
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.a <= other.a && self.b >= other.b
+    }
+
+    pub fn intersect(&self, other: &Self) -> bool {
+        (self.a >= other.a && self.a <= other.b) || (self.b >= other.a && self.b <= other.b)
+    }
+}
+
+pub struct Pair {
+    left: Section,
+    right: Section,
+}
+
+impl Pair {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, |p| {
+            let left = Section::parse(p)?;
+            p.literal(",")?;
+            let right = Section::parse(p)?;
+
+            Ok(Self { left, right })
+        })
+    }
+
+    pub fn overlaps(&self) -> bool {
+        self.left.overlaps(&self.right) || self.right.overlaps(&self.left)
+    }
+
+    pub fn intersect(&self) -> bool {
+        self.left.intersect(&self.right) || self.right.intersect(&self.left)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Pair>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input, Pair::parse)?)
+    }
+
+    fn part_a(pairs: &Self::Input) -> Result<usize> {
+        Ok(pairs.iter().filter(|pair| pair.overlaps()).count())
+    }
+
+    fn part_b(pairs: &Self::Input) -> Result<usize> {
+        Ok(pairs.iter().filter(|pair| pair.intersect()).count())
+    }
+}
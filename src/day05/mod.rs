@@ -0,0 +1,319 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+use crate::{
+    parse::{self, ParseError, Parser},
+    Solution,
+};
+
+#[derive(Clone, Copy)]
+pub struct Crate(char);
+
+impl Crate {
+    pub fn new(input: char) -> Self {
+        Self(input)
+    }
+}
+
+impl std::fmt::Debug for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self.0)
+    }
+}
+
+struct CrateIter<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> CrateIter<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for CrateIter<'a> {
+    type Item = Option<Crate>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.len() <= self.pos {
+            None
+        } else {
+            let mut i = self.input[self.pos..].chars();
+            let l = i.next();
+            let c = i.next();
+            let r = i.next();
+
+            self.pos += 4;
+
+            match (l, c, r) {
+                (Some('['), Some(c), Some(']')) => Some(Some(Crate::new(c))),
+                _ => Some(None),
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Stack(Vec<Crate>);
+
+impl Stack {
+    pub fn empty() -> Self {
+        Self(vec![])
+    }
+
+    pub(crate) fn add(&mut self, c: Crate) {
+        let mut next = vec![c];
+        next.append(&mut self.0);
+
+        self.0 = next;
+    }
+}
+
+impl std::fmt::Debug for Stack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for c in self.0.iter() {
+            write!(f, "{:?} ", c)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Field(HashMap<usize, Stack>);
+
+impl std::fmt::Debug for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut keys = self.0.keys().cloned().collect::<Vec<_>>();
+        keys.sort();
+
+        for k in keys {
+            let stack = self.0.get(&k).unwrap();
+            write!(f, "\n{}: {:?}", k, stack)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Field {
+    pub fn parse(input: &str) -> Self {
+        let mut stacks = HashMap::new();
+
+        for line in input.lines() {
+            for (idx, c) in CrateIter::new(line).enumerate() {
+                let number = idx + 1;
+
+                let stack = stacks.entry(number).or_insert(Stack::empty());
+
+                if let Some(c) = c {
+                    stack.add(c);
+                }
+            }
+        }
+
+        Self(stacks)
+    }
+
+    fn stack(&mut self, id: usize) -> Result<&mut Stack> {
+        self.0
+            .get_mut(&id)
+            .ok_or_else(|| anyhow!("There is no stack {}", id))
+    }
+
+    /// Crates per stack, by stack number starting at 1.
+    fn heights(&self) -> Vec<usize> {
+        (1..=self.0.len()).map(|id| self.0[&id].0.len()).collect()
+    }
+
+    pub fn process_a(&mut self, m: &Move) -> Result<()> {
+        for _ in 0..m.count {
+            let c = self.stack(m.from)?.0.pop();
+            let c = c.ok_or_else(|| anyhow!("Stack {} is empty", m.from))?;
+            self.stack(m.to)?.0.push(c);
+        }
+
+        Ok(())
+    }
+
+    pub fn process_b(&mut self, m: &Move) -> Result<()> {
+        let from = self.stack(m.from)?;
+        let rest = from
+            .0
+            .len()
+            .checked_sub(m.count)
+            .ok_or_else(|| anyhow!("Stack {} holds fewer than {} crates", m.from, m.count))?;
+        let tmp = from.0.split_off(rest);
+
+        self.stack(m.to)?.0.extend(tmp);
+        Ok(())
+    }
+
+    pub fn result(&self) -> Result<String> {
+        let mut keys = self.0.keys().cloned().collect::<Vec<_>>();
+        keys.sort();
+
+        keys.iter()
+            .map(|c| {
+                self.0[c]
+                    .0
+                    .last()
+                    .map(|c| c.0)
+                    .ok_or_else(|| anyhow!("Stack {} is empty", c))
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl std::fmt::Debug for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {} [{}]", self.from, self.to, self.count)
+    }
+}
+
+impl Move {
+    /// Parses a move between the stacks `1..=stacks`.
+    pub fn parse(input: &str, stacks: usize) -> Result<Self, ParseError> {
+        Parser::parse(input, |p| {
+            let stack = |p: &mut Parser| -> Result<usize, ParseError> {
+                let start = p.offset();
+                match p.int()? {
+                    id if (1..=stacks).contains(&id) => Ok(id),
+                    _ => Err(p.error_at(format!("stack 1 to {}", stacks), start)),
+                }
+            };
+
+            p.literal("move ")?;
+            let count = p.int()?;
+            p.literal(" from ")?;
+            let from = stack(p)?;
+            p.literal(" to ")?;
+            let to = stack(p)?;
+
+            Ok(Self { count, from, to })
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    field: Field,
+    moves: Vec<Move>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (field_input, moves_input) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("moves", input, input.len()))?;
+
+        let field = Field::parse(field_input);
+        let moves_line = field_input.lines().count() + 1;
+
+        // both cranes move the same number of crates, only their order differs
+        let mut heights = field.heights();
+        let moves = parse::lines(moves_input, |line| {
+            let m = Move::parse(line, heights.len())?;
+            let (from, to) = (m.from - 1, m.to - 1);
+            if heights[from] < m.count {
+                let expected = format!("at most {} crates", heights[from]);
+                return Err(ParseError::new(expected, line, "move ".len()));
+            }
+
+            heights[from] -= m.count;
+            heights[to] += m.count;
+            Ok(m)
+        })
+        .map_err(|err| err.shift_lines(moves_line))?;
+
+        Ok(Self { field, moves })
+    }
+
+    pub fn process_a(&mut self) -> Result<()> {
+        for m in &self.moves {
+            self.field.process_a(m)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn process_b(&mut self) -> Result<()> {
+        for m in &self.moves {
+            self.field.process_b(m)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn result(&self) -> Result<String> {
+        self.field.result()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Game;
+    type A = String;
+    type B = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Game::parse(input)?)
+    }
+
+    fn part_a(game: &Self::Input) -> Result<String> {
+        let mut game = game.clone();
+        game.process_a()?;
+
+        game.result()
+    }
+
+    fn part_b(game: &Self::Input) -> Result<String> {
+        let mut game = game.clone();
+        game.process_b()?;
+
+        game.result()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_moves() {
+        let example = include_str!("../../examples/day05.txt");
+        let error = |moves: &str| {
+            let input = example.replace("move 1 from 1 to 2", moves);
+            let err = Game::parse(&input).unwrap_err();
+            (err.line(), err.column(), err.expected().to_owned())
+        };
+
+        assert_eq!(error("move 1 from 4 to 2"), (9, 13, "stack 1 to 3".into()));
+        assert_eq!(error("move 1 from 1 to 0"), (9, 18, "stack 1 to 3".into()));
+        assert_eq!(
+            error("move 3 from 2 to 1"),
+            (9, 6, "at most 0 crates".into())
+        );
+    }
+
+    #[test]
+    fn test_empty_stack() {
+        let game = Game::parse("[A]    \n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+
+        assert_eq!(
+            Day05::part_a(&game).unwrap_err().to_string(),
+            "Stack 1 is empty"
+        );
+    }
+}
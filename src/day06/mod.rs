@@ -0,0 +1,103 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+
+use crate::{
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+const MARKER_A: usize = 4;
+const MARKER_B: usize = 14;
+
+#[derive(Debug)]
+pub struct Game {
+    input: String,
+}
+
+impl Game {
+    pub fn new(input: &str) -> Self {
+        let input = input.to_owned();
+        Self { input }
+    }
+
+    /// A non-empty line of lowercase letters.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input.trim_end(), |p| {
+            let signal = p.take_while("lowercase letter", |c| c.is_ascii_lowercase())?;
+            if !p.is_empty() {
+                return Err(p.error("lowercase letter"));
+            }
+
+            Ok(Self::new(signal))
+        })
+    }
+
+    pub fn result(&self, size: usize) -> Option<usize> {
+        let limit = self.input.len().checked_sub(size.checked_sub(1)?)?;
+
+        for n in 0..limit {
+            let part = &self.input[n..n + size];
+            let set: HashSet<_> = part.chars().collect();
+            if set.len() == size {
+                return Some(n + size);
+            }
+        }
+
+        None
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Game;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Game::parse(input)?)
+    }
+
+    fn part_a(game: &Self::Input) -> Result<usize> {
+        game.result(MARKER_A)
+            .ok_or_else(|| anyhow!("Task A: non found"))
+    }
+
+    fn part_b(game: &Self::Input) -> Result<usize> {
+        game.result(MARKER_B)
+            .ok_or_else(|| anyhow!("Task B: non found"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_a() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let result_a = Game::new(input).result(4);
+        let result_b = Game::new(input).result(14);
+
+        assert_eq!(result_a, Some(7));
+        assert_eq!(result_b, Some(19));
+    }
+
+    #[test]
+    fn test_short() {
+        assert_eq!(Game::new("abc").result(4), None);
+        assert_eq!(Game::new("").result(0), None);
+
+        let game = Day06::parse("abcdefghij\n").unwrap();
+        assert_eq!(Day06::part_a(&game).unwrap(), 4);
+        assert!(Day06::part_b(&game).is_err());
+
+        let err = Game::parse("\n").unwrap_err();
+        assert_eq!((err.column(), err.expected()), (1, "lowercase letter"));
+
+        let err = Game::parse("abcdefGhijklmnop").unwrap_err();
+        assert_eq!((err.column(), err.expected()), (7, "lowercase letter"));
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, bail, Result};
+
+use super::{DirId, Filesystem, ROOT};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// The smallest single directory which frees enough.
+    Smallest,
+    /// As few directories as possible, the ones freeing the most.
+    Fewest,
+    /// As few bytes as possible.
+    LeastBytes,
+}
+
+impl Strategy {
+    pub fn parse(input: &str) -> Result<Self> {
+        match input {
+            "smallest" => Ok(Self::Smallest),
+            "fewest" => Ok(Self::Fewest),
+            "least-bytes" => Ok(Self::LeastBytes),
+            _ => bail!("Invalid strategy: {}", input),
+        }
+    }
+}
+
+/// Directories to delete, none of them inside another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub paths: Vec<String>,
+    pub freed: u64,
+}
+
+/// Picks directories to delete so that `required` bytes of a `disk` are free.
+/// The root is only deleted when no other directories free enough, otherwise
+/// it would always be the fewest.
+#[derive(Debug, Clone, Copy)]
+pub struct Planner {
+    pub disk: u64,
+    pub required: u64,
+}
+
+impl Planner {
+    pub fn new(disk: u64, required: u64) -> Self {
+        Self { disk, required }
+    }
+
+    /// Bytes which have to be deleted.
+    pub fn missing(&self, fs: &Filesystem) -> Result<u64> {
+        let used = fs.size();
+        let free = self
+            .disk
+            .checked_sub(used)
+            .ok_or_else(|| anyhow!("{} bytes are used on a disk of {}", used, self.disk))?;
+
+        Ok(self.required.saturating_sub(free))
+    }
+
+    pub fn plan(&self, fs: &Filesystem, strategy: Strategy) -> Result<Plan> {
+        let missing = self.missing(fs)?;
+        if missing == 0 {
+            return Ok(Plan {
+                paths: vec![],
+                freed: 0,
+            });
+        }
+
+        let candidates = Candidates::new(fs);
+        let taken = match strategy {
+            Strategy::Smallest => (0..candidates.ids.len())
+                .filter(|idx| candidates.sizes[*idx] >= missing)
+                .min_by_key(|idx| candidates.sizes[*idx])
+                .map(|idx| vec![idx]),
+            Strategy::Fewest => candidates.fewest(missing),
+            Strategy::LeastBytes => candidates.least_bytes(missing),
+        };
+
+        let chosen: Vec<DirId> = match taken {
+            Some(taken) => taken.into_iter().map(|idx| candidates.ids[idx]).collect(),
+            None if fs.size() >= missing => vec![ROOT],
+            None => bail!("Can't free {} bytes, {} are used", missing, fs.size()),
+        };
+
+        Ok(Plan {
+            freed: chosen.iter().map(|id| fs.dir_size(*id)).sum(),
+            paths: chosen.into_iter().map(|id| fs.path(id)).collect(),
+        })
+    }
+}
+
+/// Every directory but the root in depth first order.
+struct Candidates {
+    ids: Vec<DirId>,
+    sizes: Vec<u64>,
+    /// Index after the subtree of each candidate.
+    next: Vec<usize>,
+    /// Most bytes which can be freed from each index on.
+    reachable: Vec<u64>,
+}
+
+impl Candidates {
+    fn new(fs: &Filesystem) -> Self {
+        let mut candidates = Self {
+            ids: vec![],
+            sizes: vec![],
+            next: vec![],
+            reachable: vec![],
+        };
+
+        for sub in fs.dir(ROOT).dirs() {
+            candidates.add(fs, *sub);
+        }
+
+        let len = candidates.ids.len();
+        candidates.reachable = vec![0; len + 1];
+        for idx in (0..len).rev() {
+            let next = candidates.next[idx];
+            candidates.reachable[idx] = candidates.sizes[idx] + candidates.reachable[next];
+        }
+
+        candidates
+    }
+
+    fn add(&mut self, fs: &Filesystem, id: DirId) {
+        let idx = self.ids.len();
+        self.ids.push(id);
+        self.sizes.push(fs.dir_size(id));
+        self.next.push(0);
+
+        for sub in fs.dir(id).dirs() {
+            self.add(fs, *sub);
+        }
+
+        self.next[idx] = self.ids.len();
+    }
+
+    /// Layer `k` holds the most bytes up to `k` directories from each index on
+    /// can free, layers are added until one frees enough.
+    fn fewest(&self, missing: u64) -> Option<Vec<usize>> {
+        let len = self.ids.len();
+        let mut layers = vec![vec![0; len + 1]];
+
+        while layers.last()?[0] < missing {
+            if layers.len() > len {
+                return None;
+            }
+
+            let prev = layers.last()?;
+            let mut layer = vec![0; len + 1];
+            for idx in (0..len).rev() {
+                let take = self.sizes[idx] + prev[self.next[idx]];
+                layer[idx] = layer[idx + 1].max(take);
+            }
+
+            layers.push(layer);
+        }
+
+        let mut taken = vec![];
+        let mut idx = 0;
+        for k in (1..layers.len()).rev() {
+            if layers[k][idx] == 0 {
+                break;
+            }
+
+            while layers[k][idx] == layers[k][idx + 1] {
+                idx += 1;
+            }
+
+            taken.push(idx);
+            idx = self.next[idx];
+        }
+
+        Some(taken)
+    }
+
+    /// Branch and bound over taking or skipping each candidate in order, a
+    /// state seen before can't lead to a smaller total.
+    fn least_bytes(&self, missing: u64) -> Option<Vec<usize>> {
+        let len = self.ids.len();
+        let mut best: Option<(u64, Vec<usize>)> = None;
+        let mut seen = HashSet::new();
+        let mut taken = vec![];
+        // index, bytes taken so far, how many of `taken` are still in use and
+        // the candidate taken to get there
+        let mut stack = vec![(0, 0, 0, None)];
+
+        while let Some((idx, total, depth, took)) = stack.pop() {
+            taken.truncate(depth);
+            taken.extend(took);
+
+            if total >= missing {
+                if best.as_ref().is_none_or(|(freed, _)| total < *freed) {
+                    best = Some((total, taken.clone()));
+                }
+                if total == missing {
+                    break;
+                }
+                continue;
+            }
+
+            if idx == len || total + self.reachable[idx] < missing || !seen.insert((idx, total)) {
+                continue;
+            }
+
+            stack.push((idx + 1, total, taken.len(), None));
+
+            let take = total + self.sizes[idx];
+            if best.as_ref().is_none_or(|(freed, _)| take < *freed) {
+                stack.push((self.next[idx], take, taken.len(), Some(idx)));
+            }
+        }
+
+        best.map(|(_, taken)| taken)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{day07::Day07, Solution};
+
+    #[test]
+    fn test_strategies() {
+        let input = "$ ls\ndir a\ndir b\ndir c\n$ cd a\n$ ls\n50 x\ndir d\n$ cd d\n$ ls\n45 y\n\
+            $ cd /b\n$ ls\n40 z\n$ cd /c\n$ ls\n15 w";
+        let fs = Day07::parse(input).unwrap();
+        let plan = |strategy| Planner::new(150, 60).plan(&fs, strategy).unwrap();
+
+        assert_eq!(
+            plan(Strategy::Smallest),
+            Plan {
+                paths: vec!["/a".into()],
+                freed: 95,
+            }
+        );
+        assert_eq!(plan(Strategy::Fewest).freed, 95);
+        assert_eq!(
+            plan(Strategy::LeastBytes),
+            Plan {
+                paths: vec!["/a/d".into(), "/c".into()],
+                freed: 60,
+            }
+        );
+
+        let plan = |strategy| Planner::new(150, 100).plan(&fs, strategy);
+        assert_eq!(plan(Strategy::Smallest).unwrap().paths, ["/"]);
+        assert_eq!(plan(Strategy::Fewest).unwrap().paths, ["/a", "/b"]);
+        assert_eq!(
+            plan(Strategy::LeastBytes).unwrap().paths,
+            ["/a/d", "/b", "/c"]
+        );
+    }
+
+    #[test]
+    fn test_large() {
+        let input = "$ ls\ndir a\ndir b\ndir c\n$ cd a\n$ ls\n50000000 x\n\
+            $ cd /b\n$ ls\n30000000 y\n$ cd /c\n$ ls\n25000001 z";
+        let fs = Day07::parse(input).unwrap();
+        let plan = |strategy| Planner::new(110000000, 60000000).plan(&fs, strategy);
+
+        assert_eq!(plan(Strategy::Smallest).unwrap().paths, ["/"]);
+        assert_eq!(
+            plan(Strategy::LeastBytes).unwrap(),
+            Plan {
+                paths: vec!["/b".into(), "/c".into()],
+                freed: 55000001,
+            }
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let fs = Day07::parse(include_str!("../../examples/day07.txt")).unwrap();
+        let plan = |required| Planner::new(70000000, required).plan(&fs, Strategy::Smallest);
+
+        assert_eq!(plan(0).unwrap().paths, Vec::<String>::new());
+        assert_eq!(plan(30000000).unwrap().paths, ["/d"]);
+        assert!(Planner::new(48381165, 48381166)
+            .plan(&fs, Strategy::LeastBytes)
+            .is_err());
+        assert!(Planner::new(100, 10).plan(&fs, Strategy::Fewest).is_err());
+
+        let plan = Planner::new(48381165, 48381165);
+        for strategy in [Strategy::Smallest, Strategy::Fewest, Strategy::LeastBytes] {
+            assert_eq!(plan.plan(&fs, strategy).unwrap().paths, ["/"]);
+        }
+    }
+}
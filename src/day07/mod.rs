@@ -0,0 +1,713 @@
+use anyhow::{anyhow, bail, Result};
+use std::{
+    cell::OnceCell,
+    fmt::{Display, Formatter},
+};
+
+mod cleanup;
+
+pub use cleanup::{Plan, Planner, Strategy};
+
+use crate::{
+    output,
+    parse::{self, ParseError, Parser},
+    verbose, Solution,
+};
+
+/// A line of the terminal transcript.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    /// `$ cd PATH`, relative or absolute, `~` is the root.
+    ChangeDir(String),
+    List,
+    FileEntry(u64, String),
+    DirEntry(String),
+}
+
+impl Command {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, |p| {
+            if p.try_literal("$ ") {
+                return Self::parse_command(p);
+            }
+
+            if p.try_literal("dir ") {
+                return Ok(Self::DirEntry(parse_name(p, "directory name")?));
+            }
+
+            if p.peek().is_some_and(|c| c.is_ascii_digit()) {
+                let size = p.int()?;
+                p.literal(" ")?;
+                return Ok(Self::FileEntry(size, parse_name(p, "file name")?));
+            }
+
+            Err(p.error("command or directory entry"))
+        })
+    }
+
+    fn parse_command(p: &mut Parser) -> Result<Self, ParseError> {
+        if p.try_literal("ls") {
+            return Ok(Self::List);
+        }
+
+        p.literal("cd ")?;
+        let path = p.take_while("path", |_| true)?;
+        Ok(Self::ChangeDir(path.to_owned()))
+    }
+}
+
+/// The rest of the line, any characters but `/`.
+fn parse_name(p: &mut Parser, expected: &str) -> Result<String, ParseError> {
+    Ok(p.take_while(expected, |c| c != '/')?.to_owned())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Dir,
+    File,
+}
+
+/// A file or a directory with its total size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub path: String,
+    pub size: u64,
+    pub kind: Kind,
+}
+
+impl Entry {
+    fn dir(path: String, size: u64) -> Self {
+        Self {
+            path,
+            size,
+            kind: Kind::Dir,
+        }
+    }
+
+    fn file(path: String, size: u64) -> Self {
+        Self {
+            path,
+            size,
+            kind: Kind::File,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or("")
+    }
+}
+
+/// Conditions of `Filesystem::find`, all of the given ones must hold.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    pub kind: Option<Kind>,
+    /// Glob for the entry name, `*` matches any run of characters, `?` a
+    /// single one.
+    pub name: Option<String>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+}
+
+impl Query {
+    pub fn matches(&self, entry: &Entry) -> bool {
+        self.kind.is_none_or(|kind| kind == entry.kind)
+            && self.min_size.is_none_or(|min| entry.size >= min)
+            && self.max_size.is_none_or(|max| entry.size <= max)
+            && self
+                .name
+                .as_ref()
+                .is_none_or(|glob| glob_match(glob, entry.name()))
+    }
+}
+
+fn glob_match(glob: &str, name: &str) -> bool {
+    let glob: Vec<_> = glob.chars().collect();
+    let name: Vec<_> = name.chars().collect();
+
+    // matched[j]: the glob read so far matches the first j characters
+    let mut matched = vec![false; name.len() + 1];
+    matched[0] = true;
+
+    for g in glob {
+        let prev = matched.clone();
+        matched[0] = prev[0] && g == '*';
+
+        for j in 1..=name.len() {
+            matched[j] = match g {
+                '*' => prev[j] || matched[j - 1],
+                '?' => prev[j - 1],
+                g => prev[j - 1] && name[j - 1] == g,
+            };
+        }
+    }
+
+    matched[name.len()]
+}
+
+/// Index of a directory in its `Filesystem`.
+pub type DirId = usize;
+
+pub const ROOT: DirId = 0;
+
+pub struct File {
+    name: String,
+    size: u64,
+}
+
+impl File {
+    pub fn new(name: String, size: u64) -> Self {
+        Self { name, size }
+    }
+}
+
+impl Display for File {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "- {} (file, size={})", self.name, self.size)
+    }
+}
+
+pub struct Dir {
+    name: String,
+    parent: Option<DirId>,
+    /// `ls` ran in it, otherwise only the entries passed by `cd` are known.
+    listed: bool,
+    dirs: Vec<DirId>,
+    files: Vec<File>,
+}
+
+impl Dir {
+    fn new(name: String, parent: Option<DirId>) -> Self {
+        Self {
+            name,
+            parent,
+            listed: false,
+            dirs: vec![],
+            files: vec![],
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn parent(&self) -> Option<DirId> {
+        self.parent
+    }
+
+    /// Never listed, its size is a lower bound.
+    pub fn is_pending(&self) -> bool {
+        !self.listed
+    }
+
+    pub fn dirs(&self) -> &[DirId] {
+        &self.dirs
+    }
+
+    pub fn files(&self) -> &[File] {
+        &self.files
+    }
+
+    pub fn find_file(&self, name: &str) -> Option<&File> {
+        self.files().iter().find(|file| file.name == name)
+    }
+}
+
+fn join(dir: &str, name: &str) -> String {
+    match dir {
+        "/" => format!("/{}", name),
+        _ => format!("{}/{}", dir, name),
+    }
+}
+
+/// Directory tree kept in an arena, a directory is always stored after its
+/// parent. Total sizes are computed once and kept until the next change.
+pub struct Filesystem {
+    dirs: Vec<Dir>,
+    current: DirId,
+    sizes: OnceCell<Vec<u64>>,
+}
+
+impl Filesystem {
+    pub fn new() -> Self {
+        Self {
+            dirs: vec![Dir::new("/".into(), None)],
+            current: ROOT,
+            sizes: OnceCell::new(),
+        }
+    }
+
+    pub fn dir(&self, id: DirId) -> &Dir {
+        &self.dirs[id]
+    }
+
+    /// Absolute path, `/` for the root.
+    pub fn path(&self, id: DirId) -> String {
+        match self.dirs[id].parent {
+            Some(parent) => join(&self.path(parent), &self.dirs[id].name),
+            None => "/".into(),
+        }
+    }
+
+    /// Total size of a directory, a pending one counts what is known of it.
+    pub fn dir_size(&self, id: DirId) -> u64 {
+        let sizes = self.sizes.get_or_init(|| {
+            let mut sizes: Vec<u64> = self
+                .dirs
+                .iter()
+                .map(|dir| dir.files().iter().map(|file| file.size).sum())
+                .collect();
+
+            for id in (1..self.dirs.len()).rev() {
+                if let Some(parent) = self.dirs[id].parent {
+                    sizes[parent] += sizes[id];
+                }
+            }
+
+            sizes
+        });
+
+        sizes[id]
+    }
+
+    pub fn size(&self) -> u64 {
+        self.dir_size(ROOT)
+    }
+
+    fn find_dir(&self, id: DirId, name: &str) -> Option<DirId> {
+        let dirs = self.dirs[id].dirs();
+        dirs.iter()
+            .copied()
+            .find(|dir| self.dirs[*dir].name == name)
+    }
+
+    /// Adds a subdirectory unless it is known already, listing a directory
+    /// again is fine.
+    pub fn add_dir(&mut self, parent: DirId, name: String) -> Result<DirId> {
+        if self.dirs[parent].find_file(&name).is_some() {
+            bail!(
+                "{} is listed as a file and a directory",
+                join(&self.path(parent), &name)
+            );
+        }
+
+        if let Some(known) = self.find_dir(parent, &name) {
+            return Ok(known);
+        }
+
+        let id = self.dirs.len();
+        self.dirs.push(Dir::new(name, Some(parent)));
+        self.dirs[parent].dirs.push(id);
+        self.sizes.take();
+
+        Ok(id)
+    }
+
+    /// Adds a file unless it is known already, a known file must keep its
+    /// size.
+    pub fn add_file(&mut self, dir: DirId, file: File) -> Result<()> {
+        if self.find_dir(dir, &file.name).is_some() {
+            bail!(
+                "{} is listed as a file and a directory",
+                join(&self.path(dir), &file.name)
+            );
+        }
+
+        if let Some(known) = self.dirs[dir].find_file(&file.name) {
+            if known.size != file.size {
+                bail!(
+                    "{} is listed with sizes {} and {}",
+                    join(&self.path(dir), &file.name),
+                    known.size,
+                    file.size
+                );
+            }
+
+            return Ok(());
+        }
+
+        self.dirs[dir].files.push(file);
+        self.sizes.take();
+
+        Ok(())
+    }
+
+    pub fn process(&mut self, cmd: Command) -> Result<()> {
+        verbose!("{:?}", cmd);
+
+        match cmd {
+            Command::ChangeDir(path) => self.change_dir(&path)?,
+            Command::List => self.dirs[self.current].listed = true,
+            Command::DirEntry(name) => {
+                self.add_dir(self.current, name)?;
+            }
+            Command::FileEntry(size, name) => {
+                self.add_file(self.current, File::new(name, size))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Directories on the way which were not listed yet are created, the
+    /// shell would have refused to enter them otherwise. They stay pending
+    /// until they are listed.
+    fn change_dir(&mut self, path: &str) -> Result<()> {
+        let (mut dir, relative) = match path.strip_prefix('~') {
+            Some(rest) if rest.is_empty() || rest.starts_with('/') => (ROOT, rest),
+            _ if path.starts_with('/') => (ROOT, path),
+            _ => (self.current, path),
+        };
+
+        for name in relative.split('/') {
+            dir = match name {
+                "" | "." => continue,
+                ".." => self.dirs[dir].parent.unwrap_or(dir),
+                name => self
+                    .add_dir(dir, name.to_owned())
+                    .map_err(|_| anyhow!("Not a directory: {}", path))?,
+            };
+        }
+
+        self.current = dir;
+        Ok(())
+    }
+
+    /// Every directory with its absolute path and total size.
+    pub fn dirs(&self) -> impl Iterator<Item = (String, u64)> + '_ {
+        let mut paths: Vec<String> = Vec::with_capacity(self.dirs.len());
+
+        for dir in &self.dirs {
+            match dir.parent {
+                Some(parent) => paths.push(join(&paths[parent], &dir.name)),
+                None => paths.push("/".into()),
+            }
+        }
+
+        paths
+            .into_iter()
+            .enumerate()
+            .map(|(id, path)| (path, self.dir_size(id)))
+    }
+
+    /// Directories which were seen but never listed, their sizes are lower
+    /// bounds.
+    pub fn pending(&self) -> Vec<String> {
+        (0..self.dirs.len())
+            .filter(|id| self.dirs[*id].is_pending())
+            .map(|id| self.path(id))
+            .collect()
+    }
+
+    /// This directory, its files and then its subdirectories, depth first.
+    fn collect(&self, id: DirId, path: &str, entries: &mut Vec<Entry>) {
+        let dir = &self.dirs[id];
+        entries.push(Entry::dir(path.into(), self.dir_size(id)));
+
+        for file in dir.files() {
+            entries.push(Entry::file(join(path, &file.name), file.size));
+        }
+
+        for sub in dir.dirs() {
+            self.collect(*sub, &join(path, &self.dirs[*sub].name), entries);
+        }
+    }
+
+    /// Resolves an absolute path, `.` and `..` segments are allowed.
+    pub fn lookup(&self, path: &str) -> Result<Node> {
+        let relative = path
+            .strip_prefix('/')
+            .ok_or_else(|| anyhow!("Path is not absolute: {}", path))?;
+
+        let mut dir = ROOT;
+        let mut segments = relative
+            .split('/')
+            .filter(|segment| !segment.is_empty() && *segment != ".")
+            .peekable();
+
+        while let Some(name) = segments.next() {
+            if name == ".." {
+                dir = self.dirs[dir].parent.unwrap_or(dir);
+                continue;
+            }
+
+            if let Some(next) = self.find_dir(dir, name) {
+                dir = next;
+                continue;
+            }
+
+            let file = match segments.peek() {
+                Some(_) => None,
+                None => self.dirs[dir].find_file(name),
+            };
+
+            return match file {
+                Some(file) => Ok(Node::File(Entry::file(
+                    join(&self.path(dir), name),
+                    file.size,
+                ))),
+                None => bail!("No such file or directory: {}", path),
+            };
+        }
+
+        Ok(Node::Dir(dir))
+    }
+
+    /// Every entry below `path`, including itself.
+    pub fn entries(&self, path: &str) -> Result<Vec<Entry>> {
+        match self.lookup(path)? {
+            Node::Dir(id) => {
+                let mut entries = vec![];
+                self.collect(id, &self.path(id), &mut entries);
+                Ok(entries)
+            }
+            Node::File(entry) => Ok(vec![entry]),
+        }
+    }
+
+    /// Total size of `path` and of every directory below it, like `du`.
+    pub fn du(&self, path: &str) -> Result<Vec<Entry>> {
+        let mut entries = self.entries(path)?;
+
+        if entries[0].kind == Kind::Dir {
+            entries.retain(|entry| entry.kind == Kind::Dir);
+        }
+
+        Ok(entries)
+    }
+
+    pub fn find(&self, path: &str, query: &Query) -> Result<Vec<Entry>> {
+        let mut entries = self.entries(path)?;
+        entries.retain(|entry| query.matches(entry));
+        Ok(entries)
+    }
+
+    pub fn tree(&self, path: &str) -> Result<String> {
+        match self.lookup(path)? {
+            Node::Dir(id) => {
+                let mut out = String::new();
+                self.print(id, 0, &mut out)?;
+                Ok(out)
+            }
+            Node::File(entry) => Ok(format!("- {} (file, size={})", entry.name(), entry.size)),
+        }
+    }
+
+    fn print(&self, id: DirId, indent: usize, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        let dir = &self.dirs[id];
+        let pad = "  ".repeat(indent);
+
+        match dir.is_pending() {
+            true => write!(f, "{}- {} (dir) (pending)", pad, dir.name)?,
+            false => write!(f, "{}{} (dir size={})", pad, dir.name, self.dir_size(id))?,
+        }
+
+        for sub in dir.dirs() {
+            writeln!(f)?;
+            self.print(*sub, indent + 1, f)?;
+        }
+
+        for file in dir.files() {
+            write!(f, "\n{}  {}", pad, file)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A resolved path.
+pub enum Node {
+    Dir(DirId),
+    File(Entry),
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for Filesystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.print(ROOT, 0, f)
+    }
+}
+
+/// Size of the device in the puzzle.
+pub const DISK: u64 = 70000000;
+/// Free space the update needs.
+pub const REQUIRED: u64 = 30000000;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Filesystem;
+    type A = u64;
+    type B = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let commands = parse::lines(input, Command::parse)?;
+
+        let mut filesystem = Filesystem::new();
+
+        for (idx, cmd) in commands.into_iter().enumerate() {
+            filesystem
+                .process(cmd)
+                .map_err(|err| anyhow!("line {}: {}", idx + 1, err))?;
+        }
+
+        Ok(filesystem)
+    }
+
+    fn part_a(filesystem: &Self::Input) -> Result<u64> {
+        verbose!("{}", filesystem);
+
+        for path in filesystem.pending() {
+            verbose!("never listed, counted as empty: {}", path);
+        }
+
+        if output::is_verbose() {
+            for (name, size) in filesystem.dirs() {
+                verbose!("D: {} ({})", name, size);
+            }
+        }
+
+        let result_a = filesystem
+            .dirs()
+            .filter_map(|(_, size)| if size <= 100000 { Some(size) } else { None })
+            .sum();
+
+        Ok(result_a)
+    }
+
+    fn part_b(filesystem: &Self::Input) -> Result<u64> {
+        let plan = Planner::new(DISK, REQUIRED).plan(filesystem, Strategy::Smallest)?;
+        verbose!("delete {:?}", plan.paths);
+
+        Ok(plan.freed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Filesystem {
+        Day07::parse(include_str!("../../examples/day07.txt")).unwrap()
+    }
+
+    fn paths(entries: &[Entry]) -> Vec<(&str, u64)> {
+        entries
+            .iter()
+            .map(|entry| (entry.path.as_str(), entry.size))
+            .collect()
+    }
+
+    #[test]
+    fn test_du() {
+        let fs = example();
+
+        assert_eq!(paths(&fs.du("/a").unwrap()), [("/a", 94853), ("/a/e", 584)]);
+        assert_eq!(
+            paths(&fs.du("/a/e/../../d/j").unwrap()),
+            [("/d/j", 4060174)]
+        );
+        assert!(fs.du("/a/x").is_err());
+        assert!(fs.du("a").is_err());
+    }
+
+    #[test]
+    fn test_find() {
+        let fs = example();
+        let query = Query {
+            name: Some("*.?x?".into()),
+            ..Query::default()
+        };
+        assert_eq!(
+            paths(&fs.find("/", &query).unwrap()),
+            [("/b.txt", 14848514), ("/d/d.ext", 5626152)]
+        );
+
+        let query = Query {
+            kind: Some(Kind::Dir),
+            max_size: Some(100000),
+            ..Query::default()
+        };
+        assert_eq!(
+            paths(&fs.find("/", &query).unwrap()),
+            [("/a", 94853), ("/a/e", 584)]
+        );
+    }
+
+    #[test]
+    fn test_parse_command() {
+        let parse = |input| Command::parse(input).unwrap();
+
+        assert_eq!(
+            parse("$ cd ~/a-b/c.d"),
+            Command::ChangeDir("~/a-b/c.d".into())
+        );
+        assert_eq!(parse("dir .cache"), Command::DirEntry(".cache".into()));
+        assert_eq!(
+            parse("12 my file"),
+            Command::FileEntry(12, "my file".into())
+        );
+
+        let err = Command::parse("dir a/b").unwrap_err();
+        assert_eq!((err.column(), err.expected()), (6, "end of line"));
+    }
+
+    #[test]
+    fn test_transcript() {
+        let input =
+            "$ cd /x-1/y.z\n$ ls\n10 f\n$ cd ~\n$ ls\ndir x-1\n$ cd x-1/../x-1/y.z\n$ ls\n10 f";
+        let fs = Day07::parse(input).unwrap();
+
+        assert_eq!(
+            paths(&fs.du("/").unwrap()),
+            [("/", 10), ("/x-1", 10), ("/x-1/y.z", 10)]
+        );
+        assert_eq!(fs.pending(), ["/x-1"]);
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        let err = Day07::parse("$ ls\n10 f\n$ ls\n12 f").err().unwrap();
+        assert_eq!(err.to_string(), "line 4: /f is listed with sizes 10 and 12");
+
+        let err = Day07::parse("$ ls\n10 f\n$ cd f").err().unwrap();
+        assert_eq!(err.to_string(), "line 3: Not a directory: f");
+    }
+
+    #[test]
+    fn test_pending() {
+        let fs = Day07::parse("$ ls\ndir a\ndir b\n5 c\n$ cd b\n$ ls").unwrap();
+
+        assert_eq!(fs.pending(), ["/a"]);
+        assert_eq!(
+            paths(&fs.du("/").unwrap()),
+            [("/", 5), ("/a", 0), ("/b", 0)]
+        );
+        assert_eq!(
+            fs.tree("/").unwrap(),
+            "/ (dir size=5)\n  - a (dir) (pending)\n  b (dir size=0)\n  - c (file, size=5)"
+        );
+        assert!(fs.du("/a/x").is_err());
+
+        let fs = Day07::parse("$ cd /a/b\n$ ls\n7 c\n$ cd /\n$ ls\ndir a").unwrap();
+        assert_eq!(fs.pending(), ["/a"]);
+        assert_eq!(
+            fs.tree("/").unwrap(),
+            "/ (dir size=7)\n  - a (dir) (pending)\n    b (dir size=7)\n      - c (file, size=7)"
+        );
+    }
+
+    #[test]
+    fn test_glob() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a*b*c", "aXbbYc"));
+        assert!(!glob_match("a*b", "ab.c"));
+        assert!(!glob_match("?", ""));
+    }
+}
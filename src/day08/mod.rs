@@ -0,0 +1,108 @@
+use anyhow::Result;
+
+use crate::{grid::Grid, parse::ParseError, Solution};
+
+type Coord = (usize, usize);
+type Tree = u32;
+
+pub struct Forest {
+    grid: Grid<Tree>,
+    width: usize,
+    height: usize,
+}
+
+impl Forest {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, "digit", |c| c.to_digit(10))?;
+
+        if grid.is_empty() {
+            return Err(ParseError::new("tree heights", input, 0));
+        }
+
+        Ok(Self {
+            width: grid.width(),
+            height: grid.height(),
+            grid,
+        })
+    }
+
+    pub fn visible(&self, x: usize, y: usize) -> bool {
+        let tree = self.get(x, y);
+        let up = (0..y).all(|n| self.get(x, n) < tree);
+        let down = (y + 1..self.height).all(|n| self.get(x, n) < tree);
+        let left = (0..x).all(|n| self.get(n, y) < tree);
+        let right = (x + 1..self.width).all(|n| self.get(n, y) < tree);
+
+        up || down || left || right
+    }
+    fn score(&self, x: usize, y: usize) -> usize {
+        let up = (0..y)
+            .rev()
+            .take_while(|n| *n == y - 1 || self.get(x, *n + 1) < self.get(x, y))
+            .count();
+
+        let right = (x + 1..self.width)
+            .take_while(|n| *n == x + 1 || self.get(*n - 1, y) < self.get(x, y))
+            .count();
+
+        let down = (y + 1..self.height)
+            .take_while(|n| *n == y + 1 || self.get(x, *n - 1) < self.get(x, y))
+            .count();
+
+        let left = (0..x)
+            .rev()
+            .take_while(|n| *n == x - 1 || self.get(*n + 1, y) < self.get(x, y))
+            .count();
+
+        up * down * left * right
+    }
+
+    fn get(&self, x: usize, y: usize) -> Tree {
+        self.grid[(x, y)]
+    }
+
+    pub fn all(&self) -> impl Iterator<Item = Coord> {
+        self.grid.coords()
+    }
+
+    pub fn max_score(&self) -> usize {
+        self.all()
+            .map(|(x, y)| self.score(x, y))
+            .max()
+            .expect("Empty grid")
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Forest::parse(input)?)
+    }
+
+    fn part_a(forest: &Self::Input) -> Result<usize> {
+        Ok(forest.all().filter(|(x, y)| forest.visible(*x, *y)).count())
+    }
+
+    fn part_b(forest: &Self::Input) -> Result<usize> {
+        Ok(forest.max_score())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_score_a() {
+        let input = "555\n555\n555";
+        let forest = Forest::parse(input).unwrap();
+        assert_eq!(forest.max_score(), 1);
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::Result;
+
+use crate::{
+    geom::{Dir4, Point2},
+    parse::{self, ParseError},
+    Solution,
+};
+
+#[derive(Debug, Clone)]
+pub enum Step {
+    Up(usize),
+    Right(usize),
+    Down(usize),
+    Left(usize),
+}
+
+impl Step {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let step: fn(usize) -> Self = match input.get(..2) {
+            Some("U ") => Self::Up,
+            Some("R ") => Self::Right,
+            Some("D ") => Self::Down,
+            Some("L ") => Self::Left,
+            _ => return Err(ParseError::new("U, R, D or L", input, 0)),
+        };
+
+        let value = input[2..]
+            .parse::<usize>()
+            .map_err(|_| ParseError::new("number", input, 2))?;
+
+        if value == 0 {
+            return Err(ParseError::new("count ≥ 1", input, 2));
+        }
+
+        Ok(step(value))
+    }
+
+    pub fn dir(&self) -> Dir4 {
+        match self {
+            Step::Up(_) => Dir4::Up,
+            Step::Right(_) => Dir4::Right,
+            Step::Down(_) => Dir4::Down,
+            Step::Left(_) => Dir4::Left,
+        }
+    }
+
+    pub fn zero(&self) -> bool {
+        use Step::*;
+
+        matches!(self, Up(0) | Right(0) | Down(0) | Left(0))
+    }
+
+    pub fn dec(&mut self) {
+        use Step::*;
+
+        match self {
+            Up(n) if *n > 0 => *self = Up(*n - 1),
+            Right(n) if *n > 0 => *self = Right(*n - 1),
+            Down(n) if *n > 0 => *self = Down(*n - 1),
+            Left(n) if *n > 0 => *self = Left(*n - 1),
+            _ => panic!("Invalid dec"),
+        }
+    }
+}
+
+pub type Position = Point2<i32>;
+
+pub fn follow(tail: &mut Position, head: &Position) {
+    if tail.chebyshev(head) > 1 {
+        *tail += (*head - *tail).signum();
+    }
+}
+
+pub struct Game {
+    head: Position,
+    tail: Vec<Position>,
+
+    current: Option<Step>,
+    steps: VecDeque<Step>,
+}
+
+impl Game {
+    pub fn new<T>(steps: T, tails: usize) -> Self
+    where
+        T: Into<VecDeque<Step>>,
+    {
+        let mut steps = steps.into();
+        let head = Position::zero();
+        let tail = vec![Position::zero(); tails];
+        let current = steps.pop_front();
+
+        Self {
+            steps,
+            head,
+            tail,
+            current,
+        }
+    }
+
+    pub fn tail_visited(&mut self) -> usize {
+        let mut points: HashSet<Position> = HashSet::new();
+
+        for tail in self {
+            points.insert(tail);
+        }
+
+        points.len()
+    }
+}
+
+impl Iterator for Game {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ref v) = self.current {
+            if v.zero() {
+                self.current = self.steps.pop_front();
+            }
+        }
+
+        match self.current {
+            Some(ref mut step) => {
+                self.head = self.head.step(step.dir());
+                step.dec()
+            }
+            None => return None,
+        }
+
+        for idx in 0..self.tail.len() {
+            if idx == 0 {
+                follow(&mut self.tail[idx], &self.head);
+            } else {
+                let (l, r) = self.tail.split_at_mut(idx);
+                follow(&mut r[0], &l[idx - 1]);
+            }
+        }
+
+        Some(self.tail[self.tail.len() - 1])
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Step>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input, Step::parse)?)
+    }
+
+    fn part_a(steps: &Self::Input) -> Result<usize> {
+        let mut game_a = Game::new(steps.clone(), 1);
+        Ok(game_a.tail_visited())
+    }
+
+    fn part_b(steps: &Self::Input) -> Result<usize> {
+        let mut game_b = Game::new(steps.clone(), 9);
+        Ok(game_b.tail_visited())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn follow_1() {
+        let h = Position::new(0, 0);
+        let mut t = Position::new(0, 0);
+
+        follow(&mut t, &h);
+        assert_eq!(t, Position::new(0, 0));
+    }
+
+    #[test]
+    fn follow_2() {
+        let h = Position::new(0, 2);
+        let mut t = Position::new(0, 0);
+
+        follow(&mut t, &h);
+        assert_eq!(t, Position::new(0, 1));
+    }
+
+    #[test]
+    fn follow_3() {
+        let h = Position::new(10, 5);
+        let mut t = Position::new(8, 5);
+
+        follow(&mut t, &h);
+        assert_eq!(t, Position::new(9, 5));
+    }
+
+    #[test]
+    fn follow_4() {
+        let h = Position::new(2, 1);
+        let mut t = Position::new(0, 0);
+
+        follow(&mut t, &h);
+        assert_eq!(t, Position::new(1, 1));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!(Step::parse("R 4"), Ok(Step::Right(4))));
+
+        let err = Day09::parse("R 1\nU 0\nL 1\n").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert_eq!(err.expected(), "count ≥ 1");
+    }
+}
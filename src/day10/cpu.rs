@@ -0,0 +1,657 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::parse::{ParseError, Parser};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reg {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+impl Reg {
+    pub const ALL: [Reg; 4] = [Reg::X, Reg::Y, Reg::Z, Reg::W];
+
+    fn parse(p: &mut Parser) -> Result<Self, ParseError> {
+        let reg = match p.peek() {
+            Some('x') => Reg::X,
+            Some('y') => Reg::Y,
+            Some('z') => Reg::Z,
+            Some('w') => Reg::W,
+            _ => return Err(p.error("register")),
+        };
+
+        p.literal(&reg.to_string())?;
+        Ok(reg)
+    }
+}
+
+impl Display for Reg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Reg::X => "x",
+            Reg::Y => "y",
+            Reg::Z => "z",
+            Reg::W => "w",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Operand which is either a register or a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    Reg(Reg),
+    Num(i64),
+}
+
+impl Value {
+    fn parse(p: &mut Parser) -> Result<Self, ParseError> {
+        match p.peek() {
+            Some(c) if c == '-' || c.is_ascii_digit() => p.int().map(Value::Num),
+            _ => Reg::parse(p)
+                .map(Value::Reg)
+                .map_err(|_| p.error("register or number")),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Reg(reg) => write!(f, "{}", reg),
+            Value::Num(num) => write!(f, "{}", num),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Noop,
+    Addx,
+    Add,
+    Set,
+    Mul,
+    Jmp,
+    Jnz,
+}
+
+/// Mnemonic and default cycle cost of every opcode.
+pub const OPCODES: [(Opcode, &str, usize); 7] = [
+    (Opcode::Noop, "noop", 1),
+    (Opcode::Addx, "addx", 2),
+    (Opcode::Add, "add", 2),
+    (Opcode::Set, "set", 1),
+    (Opcode::Mul, "mul", 3),
+    (Opcode::Jmp, "jmp", 1),
+    (Opcode::Jnz, "jnz", 1),
+];
+
+impl Opcode {
+    pub fn name(&self) -> &'static str {
+        OPCODES[*self as usize].1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cmd {
+    Noop,
+    /// `addx V`, adds to `x`.
+    Add(i32),
+    AddReg(Reg, Value),
+    Set(Reg, Value),
+    Mul(Reg, Value),
+    Jmp(String),
+    /// Jumps to the label unless the value is zero.
+    Jnz(Value, String),
+}
+
+impl Cmd {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, Cmd::parse_cmd)
+    }
+
+    fn parse_cmd(p: &mut Parser) -> Result<Self, ParseError> {
+        let start = p.offset();
+        let name = p.word("instruction")?;
+        let (opcode, _, _) = OPCODES
+            .iter()
+            .find(|(_, known, _)| *known == name)
+            .ok_or_else(|| p.error_at("instruction", start))?;
+
+        if *opcode != Opcode::Noop {
+            p.literal(" ")?;
+        }
+
+        let reg_value = |p: &mut Parser| -> Result<(Reg, Value), ParseError> {
+            let reg = Reg::parse(p)?;
+            p.literal(" ")?;
+            Ok((reg, Value::parse(p)?))
+        };
+
+        let cmd = match opcode {
+            Opcode::Noop => Cmd::Noop,
+            Opcode::Addx => Cmd::Add(p.int()?),
+            Opcode::Add => reg_value(p).map(|(reg, value)| Cmd::AddReg(reg, value))?,
+            Opcode::Set => reg_value(p).map(|(reg, value)| Cmd::Set(reg, value))?,
+            Opcode::Mul => reg_value(p).map(|(reg, value)| Cmd::Mul(reg, value))?,
+            Opcode::Jmp => Cmd::Jmp(p.word("label")?.to_owned()),
+            Opcode::Jnz => {
+                let value = Value::parse(p)?;
+                p.literal(" ")?;
+                Cmd::Jnz(value, p.word("label")?.to_owned())
+            }
+        };
+
+        Ok(cmd)
+    }
+
+    pub fn opcode(&self) -> Opcode {
+        match self {
+            Cmd::Noop => Opcode::Noop,
+            Cmd::Add(_) => Opcode::Addx,
+            Cmd::AddReg(..) => Opcode::Add,
+            Cmd::Set(..) => Opcode::Set,
+            Cmd::Mul(..) => Opcode::Mul,
+            Cmd::Jmp(_) => Opcode::Jmp,
+            Cmd::Jnz(..) => Opcode::Jnz,
+        }
+    }
+
+    fn label(&self) -> Option<&str> {
+        match self {
+            Cmd::Jmp(label) | Cmd::Jnz(_, label) => Some(label),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Cmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.opcode().name();
+
+        match self {
+            Cmd::Noop => write!(f, "{}", name),
+            Cmd::Add(value) => write!(f, "{} {}", name, value),
+            Cmd::AddReg(reg, value) | Cmd::Set(reg, value) | Cmd::Mul(reg, value) => {
+                write!(f, "{} {} {}", name, reg, value)
+            }
+            Cmd::Jmp(label) => write!(f, "{} {}", name, label),
+            Cmd::Jnz(value, label) => write!(f, "{} {} {}", name, value, label),
+        }
+    }
+}
+
+/// Instructions with the labels pointing into them, `name:` on a line of its
+/// own labels the next instruction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    cmds: Vec<Cmd>,
+    labels: HashMap<String, usize>,
+}
+
+impl Program {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut program = Program::default();
+        let mut jumps = vec![];
+
+        for (idx, line) in input.lines().enumerate() {
+            let label = Parser::parse(line, |p| {
+                let name = p.word("label")?;
+                p.literal(":")?;
+                Ok(name)
+            });
+
+            match label {
+                Ok(label) => {
+                    let pc = program.cmds.len();
+                    if program.labels.insert(label.to_owned(), pc).is_some() {
+                        return Err(ParseError::new("unique label", line, 0).shift_lines(idx));
+                    }
+                }
+                Err(_) => {
+                    let cmd = Cmd::parse(line).map_err(|err| err.shift_lines(idx))?;
+                    if cmd.label().is_some() {
+                        jumps.push((idx, line));
+                    }
+                    program.cmds.push(cmd);
+                }
+            }
+        }
+
+        for (idx, line) in jumps {
+            let label = line.rsplit(' ').next().unwrap_or(line);
+            if !program.labels.contains_key(label) {
+                let offset = line.len() - label.len();
+                return Err(ParseError::new("known label", line, offset).shift_lines(idx));
+            }
+        }
+
+        Ok(program)
+    }
+
+    pub fn cmds(&self) -> &[Cmd] {
+        &self.cmds
+    }
+}
+
+/// Prints the program the way [`Program::parse`] reads it.
+impl Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut labels: Vec<_> = self.labels.iter().collect();
+        labels.sort_by_key(|(name, pc)| (**pc, name.as_str()));
+        let mut labels = labels.into_iter().peekable();
+
+        for pc in 0..=self.cmds.len() {
+            while let Some((name, _)) = labels.next_if(|(_, at)| **at == pc) {
+                writeln!(f, "{}:", name)?;
+            }
+
+            if let Some(cmd) = self.cmds.get(pc) {
+                writeln!(f, "{}", cmd)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Cycle cost of each opcode.
+#[derive(Debug, Clone)]
+pub struct InstructionSet {
+    cycles: [usize; OPCODES.len()],
+}
+
+impl InstructionSet {
+    pub fn cycles(&self, opcode: Opcode) -> usize {
+        self.cycles[opcode as usize]
+    }
+
+    pub fn set_cycles(&mut self, opcode: Opcode, cycles: usize) {
+        self.cycles[opcode as usize] = cycles.max(1);
+    }
+}
+
+impl Default for InstructionSet {
+    fn default() -> Self {
+        Self {
+            cycles: OPCODES.map(|(_, _, cycles)| cycles),
+        }
+    }
+}
+
+/// State during a single cycle, an instruction takes effect at the end of its
+/// last cycle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuInfo {
+    pub step: usize,
+    pub pc: usize,
+    pub xreg: i64,
+}
+
+impl Display for CpuInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cycle {} pc {} x {}", self.step, self.pc, self.xreg)
+    }
+}
+
+/// Why [`Cpu::run`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    Breakpoint(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    program: Program,
+    set: InstructionSet,
+    regs: [i64; 4],
+    pc: usize,
+    step: usize,
+    /// Cycles left of the instruction at `pc`, zero before it started.
+    busy: usize,
+    breakpoints: HashSet<usize>,
+    /// Set once the iterator yielded an overflow, it ends there.
+    errored: bool,
+}
+
+impl Cpu {
+    pub fn new(program: Program) -> Self {
+        Self::with_instruction_set(program, InstructionSet::default())
+    }
+
+    pub fn with_instruction_set(program: Program, set: InstructionSet) -> Self {
+        Self {
+            program,
+            set,
+            regs: [1, 0, 0, 0],
+            pc: 0,
+            step: 0,
+            busy: 0,
+            breakpoints: HashSet::new(),
+            errored: false,
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self::new(Program::parse(input)?))
+    }
+
+    pub fn reg(&self, reg: Reg) -> i64 {
+        self.regs[reg as usize]
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.cmds.len()
+    }
+
+    /// Stops [`Cpu::run`] before the instruction at `pc` starts.
+    pub fn add_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn remove_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.remove(&pc);
+    }
+
+    /// Runs a single cycle, the CPU is left unchanged if its instruction
+    /// overflows a register.
+    pub fn step(&mut self) -> Result<Option<CpuInfo>> {
+        let cmd = match self.program.cmds.get(self.pc) {
+            Some(cmd) => cmd,
+            None => return Ok(None),
+        };
+
+        let busy = match self.busy {
+            0 => self.set.cycles(cmd.opcode()),
+            busy => busy,
+        };
+        let info = CpuInfo {
+            step: self.step + 1,
+            pc: self.pc,
+            xreg: self.reg(Reg::X),
+        };
+
+        if busy == 1 {
+            self.execute()?;
+        }
+        self.step = info.step;
+        self.busy = busy - 1;
+
+        Ok(Some(info))
+    }
+
+    /// Runs until the program ends or a breakpoint is reached, a breakpoint
+    /// at the current instruction is passed.
+    pub fn run(&mut self) -> Result<Stop> {
+        while self.step()?.is_some() {
+            if self.busy == 0 && self.breakpoints.contains(&self.pc) && !self.is_halted() {
+                return Ok(Stop::Breakpoint(self.pc));
+            }
+        }
+
+        Ok(Stop::Halted)
+    }
+
+    fn execute(&mut self) -> Result<()> {
+        let cmd = &self.program.cmds[self.pc];
+        let regs = self.regs;
+        let value = |value: &Value| match value {
+            Value::Reg(reg) => regs[*reg as usize],
+            Value::Num(num) => *num,
+        };
+        let pc = self.pc;
+        let overflow = || anyhow!("'{}' at pc {} overflows", cmd, pc);
+
+        let mut next = self.pc + 1;
+        match cmd {
+            Cmd::Noop => {}
+            Cmd::Add(num) => {
+                self.regs[Reg::X as usize] = regs[Reg::X as usize]
+                    .checked_add(*num as i64)
+                    .ok_or_else(overflow)?
+            }
+            Cmd::AddReg(reg, v) => {
+                self.regs[*reg as usize] = regs[*reg as usize]
+                    .checked_add(value(v))
+                    .ok_or_else(overflow)?
+            }
+            Cmd::Set(reg, v) => self.regs[*reg as usize] = value(v),
+            Cmd::Mul(reg, v) => {
+                self.regs[*reg as usize] = regs[*reg as usize]
+                    .checked_mul(value(v))
+                    .ok_or_else(overflow)?
+            }
+            Cmd::Jmp(label) => next = self.program.labels[label],
+            Cmd::Jnz(v, label) => {
+                if value(v) != 0 {
+                    next = self.program.labels[label];
+                }
+            }
+        }
+
+        self.pc = next;
+        Ok(())
+    }
+}
+
+/// Cycles until the program ends, an overflow is yielded once and ends the
+/// iteration as the CPU can't go on.
+impl Iterator for Cpu {
+    type Item = Result<CpuInfo>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.errored {
+            return None;
+        }
+
+        let result = self.step().transpose();
+        self.errored = matches!(result, Some(Err(_)));
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    const LABELS: [&str; 3] = ["start", "loop", "end_2"];
+
+    impl Arbitrary for Value {
+        fn arbitrary(g: &mut Gen) -> Self {
+            match bool::arbitrary(g) {
+                true => Value::Reg(*g.choose(&Reg::ALL).unwrap()),
+                false => Value::Num(i64::arbitrary(g)),
+            }
+        }
+    }
+
+    impl Arbitrary for Program {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % g.size();
+            let mut labels = HashMap::new();
+            for name in LABELS {
+                if bool::arbitrary(g) {
+                    labels.insert(name.to_owned(), usize::arbitrary(g) % (len + 1));
+                }
+            }
+            let names: Vec<_> = labels.keys().cloned().collect();
+
+            let cmds = (0..len)
+                .map(|_| {
+                    let reg = *g.choose(&Reg::ALL).unwrap();
+                    let label = g.choose(&names).cloned();
+
+                    match (u8::arbitrary(g) % 7, label) {
+                        (1, _) => Cmd::Add(i32::arbitrary(g)),
+                        (2, _) => Cmd::AddReg(reg, Value::arbitrary(g)),
+                        (3, _) => Cmd::Set(reg, Value::arbitrary(g)),
+                        (4, _) => Cmd::Mul(reg, Value::arbitrary(g)),
+                        (5, Some(label)) => Cmd::Jmp(label),
+                        (6, Some(label)) => Cmd::Jnz(Value::arbitrary(g), label),
+                        _ => Cmd::Noop,
+                    }
+                })
+                .collect();
+
+            Program { cmds, labels }
+        }
+    }
+
+    /// Lines glued together from pieces of valid and invalid instructions.
+    #[derive(Debug, Clone)]
+    struct Source(String);
+
+    impl Arbitrary for Source {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let pieces = [
+                "noop",
+                "addx",
+                "add",
+                "set",
+                "mul",
+                "jmp",
+                "jnz",
+                " ",
+                "x",
+                "w",
+                "-",
+                "7",
+                "99999999999999999999",
+                ":",
+                "loop",
+                "\n",
+                "é",
+                "",
+            ];
+            let len = usize::arbitrary(g) % g.size();
+
+            Source((0..len).map(|_| *g.choose(&pieces).unwrap()).collect())
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let example = include_str!("../../examples/day10.txt");
+        assert_eq!(Program::parse(example).unwrap().to_string(), example);
+
+        let input = "set y 3\nloop:\nmul x 2\nadd y -1\njnz y loop\nend:\n";
+        assert_eq!(Program::parse(input).unwrap().to_string(), input);
+
+        fn round_trip(program: Program) -> bool {
+            Program::parse(&program.to_string()) == Ok(program)
+        }
+
+        QuickCheck::new()
+            .tests(500)
+            .quickcheck(round_trip as fn(Program) -> bool);
+    }
+
+    #[test]
+    fn test_parse_total() {
+        fn reparse(source: Source) -> bool {
+            match Program::parse(&source.0) {
+                Ok(program) => Program::parse(&program.to_string()) == Ok(program),
+                Err(_) => true,
+            }
+        }
+
+        fn any(input: String) -> bool {
+            let _ = Program::parse(&input);
+            input.lines().for_each(|line| {
+                let _ = Cmd::parse(line);
+            });
+            true
+        }
+
+        QuickCheck::new()
+            .tests(2000)
+            .quickcheck(reparse as fn(Source) -> bool);
+        QuickCheck::new().quickcheck(any as fn(String) -> bool);
+    }
+
+    #[test]
+    fn test_addx() {
+        let mut cpu = Cpu::parse("noop\naddx 3\naddx -5").unwrap();
+        let xs: Vec<_> = cpu.by_ref().map(|info| info.unwrap().xreg).collect();
+
+        assert_eq!(xs, [1, 1, 1, 4, 4]);
+        assert_eq!(cpu.reg(Reg::X), -1);
+    }
+
+    #[test]
+    fn test_loop() {
+        let input = "set y 3\nloop:\nmul x 2\nadd y -1\njnz y loop\nset z x";
+        let mut cpu = Cpu::parse(input).unwrap();
+        cpu.add_breakpoint(4);
+
+        assert_eq!(cpu.run().unwrap(), Stop::Breakpoint(4));
+        assert_eq!(cpu.reg(Reg::X), 8);
+        assert_eq!(cpu.run().unwrap(), Stop::Halted);
+        assert_eq!(cpu.reg(Reg::Z), 8);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "set x 9999999999\nloop:\nmul x x\njmp loop";
+        let mut cpu = Cpu::parse(input).unwrap();
+
+        let err = cpu.run().unwrap_err();
+        assert_eq!(err.to_string(), "'mul x x' at pc 1 overflows");
+        assert_eq!((cpu.pc(), cpu.reg(Reg::X)), (1, 9999999999));
+        assert!(cpu.step().is_err());
+    }
+
+    #[test]
+    fn test_overflow_ends() {
+        let input = "set x 9999999999\nloop:\nmul x x\njmp loop";
+        let cpu = Cpu::parse(input).unwrap();
+        assert_eq!(cpu.clone().count(), 4);
+
+        let errors: Vec<_> = cpu.filter_map(Result::err).collect();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_cycles() {
+        let mut set = InstructionSet::default();
+        set.set_cycles(Opcode::Noop, 3);
+        let cpu = Cpu::with_instruction_set(Program::parse("noop\naddx 1").unwrap(), set);
+
+        assert_eq!(cpu.count(), 5);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Program::parse("jmp start\nnoop").unwrap_err();
+        assert_eq!(
+            (err.line(), err.column(), err.expected()),
+            (1, 5, "known label")
+        );
+
+        let err = Program::parse("noop\nadd q 1").unwrap_err();
+        assert_eq!(
+            (err.line(), err.column(), err.expected()),
+            (2, 5, "register")
+        );
+
+        let err = Program::parse("loop:\nnoop\nloop:\njmp loop").unwrap_err();
+        assert_eq!(
+            (err.line(), err.column(), err.expected()),
+            (3, 1, "unique label")
+        );
+    }
+}
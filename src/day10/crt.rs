@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+
+use super::CpuInfo;
+use crate::{
+    grid::Grid,
+    parse::{self, ParseError},
+};
+
+pub const WIDTH: usize = 40;
+pub const HEIGHT: usize = 6;
+
+/// Letters of the font, 4 pixels wide with a blank column in between.
+const LETTER_WIDTH: usize = 5;
+
+#[rustfmt::skip]
+const FONT: [(char, [&str; HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Column and row drawn during cycle `step`, counted from 1.
+pub(super) fn pixel(step: usize) -> Result<(usize, usize)> {
+    if !(1..=WIDTH * HEIGHT).contains(&step) {
+        bail!("Cycle {} is not on the screen", step);
+    }
+
+    let idx = step - 1;
+    Ok((idx % WIDTH, idx / WIDTH))
+}
+
+/// Framebuffer of the CRT, `true` for a lit pixel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crt {
+    pixels: Grid<bool>,
+}
+
+impl Crt {
+    /// Draws one pixel per cycle, lit if the sprite at `x` covers it.
+    pub fn render<I>(cycles: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = Result<CpuInfo>>,
+    {
+        let mut pixels = Grid::new(WIDTH, HEIGHT, false);
+
+        for info in cycles.into_iter().take(WIDTH * HEIGHT) {
+            let info = info?;
+            let (x, y) = pixel(info.step)?;
+            pixels[(x, y)] = (x as i64 - info.xreg).abs() <= 1;
+        }
+
+        Ok(Self { pixels })
+    }
+
+    /// Reads an image of `#` and `.`.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let pixels = parse::grid(input, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Self { pixels })
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    /// Reads the letters on the screen.
+    pub fn decode(&self) -> Result<String> {
+        if self.pixels.height() != HEIGHT {
+            bail!(
+                "Letters are {} pixels high, not {}",
+                HEIGHT,
+                self.pixels.height()
+            );
+        }
+
+        (0..self.pixels.width().div_ceil(LETTER_WIDTH))
+            .map(|idx| self.letter(idx * LETTER_WIDTH))
+            .collect()
+    }
+
+    fn letter(&self, left: usize) -> Result<char> {
+        let matches = |glyph: &[&str; HEIGHT]| {
+            glyph.iter().enumerate().all(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .all(|(x, c)| self.pixels.get((left + x, y)) == Some(&(c == '#')))
+            })
+        };
+
+        match FONT.iter().find(|(_, glyph)| matches(glyph)) {
+            Some((letter, _)) => Ok(*letter),
+            None => bail!("Unknown letter at column {}", left),
+        }
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pixels.map(|lit| if *lit { '#' } else { '.' }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day10::Cpu;
+
+    #[test]
+    fn test_render() {
+        let cpu = Cpu::parse(include_str!("../../examples/day10.txt")).unwrap();
+        let crt = Crt::render(cpu).unwrap();
+        let image = crt.to_string();
+
+        assert_eq!(
+            image.lines().next(),
+            Some("##..##..##..##..##..##..##..##..##..##..")
+        );
+        assert_eq!(Crt::parse(&image).unwrap(), crt);
+        assert!(crt.decode().is_err());
+    }
+
+    #[test]
+    fn test_render_steps() {
+        let info = |step| {
+            Ok(CpuInfo {
+                step,
+                pc: 0,
+                xreg: 1,
+            })
+        };
+
+        assert!(Crt::render([info(0)]).is_err());
+        assert!(Crt::render([info(WIDTH * HEIGHT + 1)]).is_err());
+        assert!(Crt::render([info(WIDTH * HEIGHT), info(1)]).is_ok());
+    }
+
+    #[test]
+    fn test_decode() {
+        let image = "\
+            #..#.####.#....#.....##..\n\
+            #..#.#....#....#....#..#.\n\
+            ####.###..#....#....#..#.\n\
+            #..#.#....#....#....#..#.\n\
+            #..#.#....#....#....#..#.\n\
+            #..#.####.####.####..##..";
+
+        assert_eq!(Crt::parse(image).unwrap().decode().unwrap(), "HELLO");
+        assert!(Crt::parse("####\n#...").unwrap().decode().is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use std::{io::Write, thread, time::Duration};
+
+use anyhow::Result;
+
+use super::{
+    crt::{pixel, HEIGHT, WIDTH},
+    CpuInfo, Crt,
+};
+use crate::grid::Grid;
+
+const LIT: u8 = 0xff;
+const DARK: u8 = 0x20;
+
+impl Crt {
+    /// Grey values of the screen with every pixel scaled to a square.
+    fn grey(&self, scale: usize) -> (usize, usize, Vec<u8>) {
+        let scale = scale.max(1);
+        let pixels = self.pixels();
+        let (width, height) = (pixels.width() * scale, pixels.height() * scale);
+
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x / scale, y / scale)))
+            .map(|coord| if pixels[coord] { LIT } else { DARK })
+            .collect();
+
+        (width, height, data)
+    }
+
+    /// Writes a binary PPM image.
+    pub fn write_ppm<W: Write>(&self, mut out: W, scale: usize) -> Result<()> {
+        let (width, height, data) = self.grey(scale);
+        write!(out, "P6\n{} {}\n255\n", width, height)?;
+
+        let rgb: Vec<u8> = data.iter().flat_map(|grey| [*grey; 3]).collect();
+        out.write_all(&rgb)?;
+        out.flush()?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, out: W, scale: usize) -> Result<()> {
+        let (width, height, data) = self.grey(scale);
+
+        let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&data)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+/// Redraws the screen with ANSI escapes after every cycle, the sprite is
+/// shown on the row being drawn.
+pub fn replay<I, W>(cycles: I, mut out: W, delay: Duration) -> Result<()>
+where
+    I: IntoIterator<Item = Result<CpuInfo>>,
+    W: Write,
+{
+    let mut drawn = Grid::new(WIDTH, HEIGHT, None);
+    write!(out, "\x1b[2J")?;
+
+    for info in cycles.into_iter().take(WIDTH * HEIGHT) {
+        let info = info?;
+        let (x, y) = pixel(info.step)?;
+        drawn[(x, y)] = Some((x as i64 - info.xreg).abs() <= 1);
+
+        write!(out, "\x1b[H")?;
+        for row in 0..HEIGHT {
+            for col in 0..WIDTH {
+                let sprite = row == y && (col as i64 - info.xreg).abs() <= 1;
+                let cell = match drawn[(col, row)] {
+                    Some(true) => "\x1b[1;32m#",
+                    Some(false) => "\x1b[2m.",
+                    None => " ",
+                };
+
+                match sprite {
+                    true => write!(out, "\x1b[43m{}\x1b[0m", cell)?,
+                    false => write!(out, "{}\x1b[0m", cell)?,
+                }
+            }
+            writeln!(out)?;
+        }
+
+        writeln!(out, "{}\x1b[K", info)?;
+        out.flush()?;
+
+        if !delay.is_zero() {
+            thread::sleep(delay);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ppm() {
+        let crt = Crt::parse("#.\n.#").unwrap();
+        let mut out = vec![];
+        crt.write_ppm(&mut out, 2).unwrap();
+
+        let (header, data) = out.split_at(11);
+        assert_eq!(header, b"P6\n4 4\n255\n");
+        assert_eq!(data.len(), 4 * 4 * 3);
+        assert_eq!(
+            &data[..12],
+            &[LIT, LIT, LIT, LIT, LIT, LIT, DARK, DARK, DARK, DARK, DARK, DARK]
+        );
+    }
+
+    #[test]
+    fn test_replay() {
+        let cycles = (1..=3).map(|step| {
+            Ok(CpuInfo {
+                step,
+                pc: 0,
+                xreg: 1,
+            })
+        });
+        let mut out = vec![];
+        replay(cycles, &mut out, Duration::ZERO).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[H").count(), 3);
+        assert!(out.ends_with("cycle 3 pc 0 x 1\x1b[K\n"));
+
+        let cycles = [0, 241].map(|step| {
+            Ok(CpuInfo {
+                step,
+                pc: 0,
+                xreg: 1,
+            })
+        });
+        for info in cycles {
+            assert!(replay([info], vec![], Duration::ZERO).is_err());
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use std::collections::HashSet;
+
+use crate::{verbose, Solution};
+
+mod cpu;
+mod crt;
+mod export;
+
+pub use cpu::{Cmd, Cpu, CpuInfo, InstructionSet, Opcode, Program, Reg, Stop, Value, OPCODES};
+pub use crt::Crt;
+pub use export::replay;
+
+pub struct Day10;
+
+const KEY_POINTS: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Cpu;
+    type A = i64;
+    type B = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Cpu::parse(input)?)
+    }
+
+    fn part_a(cpu: &Self::Input) -> Result<i64> {
+        let key_points: HashSet<usize> = KEY_POINTS.into();
+        let last = KEY_POINTS[KEY_POINTS.len() - 1];
+        // programs may loop forever, so stop after the last key cycle
+        let list = cpu
+            .clone()
+            .take_while(|i| i.as_ref().map_or(true, |i| i.step <= last))
+            .filter(|i| i.as_ref().map_or(true, |i| key_points.contains(&i.step)))
+            .map(|i| i.map(|i| i.step as i64 * i.xreg));
+
+        list.sum()
+    }
+
+    fn part_b(cpu: &Self::Input) -> Result<String> {
+        let crt = Crt::render(cpu.clone())?;
+        verbose!("{}", crt);
+
+        crt.decode()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_endless_loop() {
+        let cpu = Day10::parse("addx 2\nloop:\nnoop\njmp loop").unwrap();
+
+        assert_eq!(
+            Day10::part_a(&cpu).unwrap(),
+            3 * (20 + 60 + 100 + 140 + 180 + 220)
+        );
+    }
+}
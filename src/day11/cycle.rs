@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use super::{Game, Reduce, Worry};
+
+/// Rounds of a single item, which doesn't depend on any other item.
+struct Path {
+    /// Monkey holding the item and its worry level at the start of each
+    /// round.
+    states: Vec<(usize, Worry)>,
+    /// Monkeys inspecting the item, by round.
+    visits: Vec<usize>,
+    offsets: Vec<usize>,
+}
+
+impl Path {
+    fn count(&self, rounds: std::ops::Range<usize>, times: u64, counts: &mut [u64]) -> Result<()> {
+        let visits = self.offsets[rounds.start]..self.offsets[rounds.end];
+        for monkey in &self.visits[visits] {
+            counts[*monkey] = counts[*monkey]
+                .checked_add(times)
+                .ok_or_else(|| anyhow!("Inspections of monkey {} overflow", monkey))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Game {
+    /// Runs `rounds` more rounds. With [`super::Policy::Modulo`] an item
+    /// only has finitely many states, so each one is followed until it
+    /// repeats a state and the remaining rounds are counted in closed form.
+    /// Items may be held in a different order than turn by turn. Traced
+    /// games are always run turn by turn.
+    pub fn run_rounds(&mut self, rounds: u64) -> Result<()> {
+        if self.trace.is_some() || !matches!(self.reduce, Reduce::Modulo(_)) {
+            for _ in 0..rounds {
+                self.turn()?;
+            }
+
+            return Ok(());
+        }
+
+        let mut counts = vec![0; self.monkeys.len()];
+        let mut held = vec![vec![]; self.monkeys.len()];
+
+        for (monkey, worry) in self.items() {
+            let (end, worry) = self.follow(monkey, worry, rounds, &mut counts)?;
+            held[end].push(worry);
+        }
+
+        let turn = usize::try_from(rounds)
+            .ok()
+            .and_then(|rounds| self.turn.checked_add(rounds))
+            .ok_or_else(|| anyhow!("Turn counter overflows after {} more rounds", rounds))?;
+        let inspections = self
+            .monkeys
+            .iter()
+            .zip(&counts)
+            .map(|(monkey, count)| {
+                usize::try_from(*count)
+                    .ok()
+                    .and_then(|count| monkey.inspect_count.checked_add(count))
+                    .ok_or_else(|| anyhow!("Inspections of monkey {} overflow", monkey.id))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for (monkey, (items, count)) in self
+            .monkeys
+            .iter_mut()
+            .zip(held.into_iter().zip(inspections))
+        {
+            monkey.items = items;
+            monkey.inspect_count = count;
+        }
+        self.turn = turn;
+
+        Ok(())
+    }
+
+    fn items(&self) -> Vec<(usize, Worry)> {
+        self.monkeys
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, monkey)| monkey.items.iter().map(move |item| (idx, item.clone())))
+            .collect()
+    }
+
+    /// Adds the inspections of an item over `rounds` rounds to `counts`, and
+    /// tells where it ends up.
+    fn follow(
+        &self,
+        monkey: usize,
+        worry: Worry,
+        rounds: u64,
+        counts: &mut [u64],
+    ) -> Result<(usize, Worry)> {
+        let mut path = Path {
+            states: vec![(monkey, worry)],
+            visits: vec![],
+            offsets: vec![0],
+        };
+        let mut seen = HashMap::new();
+
+        let start = loop {
+            let round = path.states.len() - 1;
+            if round as u64 == rounds {
+                path.count(0..round, 1, counts)?;
+                return Ok(path.states.swap_remove(round));
+            }
+
+            if let Some(start) = seen.insert(path.states[round].clone(), round) {
+                break start;
+            }
+
+            let (monkey, worry) = &path.states[round];
+            let next = self.round(*monkey, worry, &mut path.visits)?;
+            path.offsets.push(path.visits.len());
+            path.states.push(next);
+        };
+
+        let len = path.states.len() - 1 - start;
+        let left = rounds - start as u64;
+        let (cycles, rest) = (left / len as u64, (left % len as u64) as usize);
+
+        path.count(0..start, 1, counts)?;
+        path.count(start..start + len, cycles, counts)?;
+        path.count(start..start + rest, 1, counts)?;
+
+        Ok(path.states.swap_remove(start + rest))
+    }
+
+    /// Throws an item until it lands at a monkey whose turn is over.
+    fn round(
+        &self,
+        mut monkey: usize,
+        worry: &Worry,
+        visits: &mut Vec<usize>,
+    ) -> Result<(usize, Worry)> {
+        let mut worry = worry.clone();
+
+        loop {
+            visits.push(monkey);
+            let (next, target) = self.monkeys[monkey].process(&worry, self.reduce)?;
+            worry = next;
+
+            if target <= monkey {
+                return Ok((target, worry));
+            }
+            monkey = target;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        day11::{Day11, Monkey, Policy},
+        Solution,
+    };
+
+    #[test]
+    fn test_run_rounds() {
+        let game = Day11::parse(include_str!("../../examples/day11.txt")).unwrap();
+        let game = game.with_policy(Policy::Modulo).unwrap();
+
+        for rounds in [0, 1, 20, 1000, 10000] {
+            let mut fast = game.clone();
+            fast.run_rounds(rounds).unwrap();
+
+            let mut slow = game.clone();
+            for _ in 0..rounds {
+                slow.turn().unwrap();
+            }
+
+            let state = |game: &Game| -> Vec<(usize, Vec<String>)> {
+                let held = |m: &Monkey| {
+                    let mut items: Vec<_> = m.items.iter().map(|w| w.to_string()).collect();
+                    items.sort();
+                    items
+                };
+                game.monkeys
+                    .iter()
+                    .map(|m| (m.inspect_count, held(m)))
+                    .collect()
+            };
+            assert_eq!(state(&fast), state(&slow));
+        }
+
+        let mut game = game;
+        game.run_rounds(1_000_000_000).unwrap();
+        assert_eq!(game.turn, 1_000_000_000);
+
+        let before = game.clone();
+        let err = game.run_rounds(u64::MAX).unwrap_err();
+        assert!(err.to_string().ends_with("overflow"), "{}", err);
+        assert_eq!(game.turn, before.turn);
+    }
+}
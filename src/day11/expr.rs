@@ -0,0 +1,225 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+
+use super::Worry;
+use crate::parse::{ParseError, Parser};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl BinOp {
+    fn precedence(&self) -> u8 {
+        match self {
+            BinOp::Add | BinOp::Sub => 1,
+            BinOp::Mul | BinOp::Div | BinOp::Rem => 2,
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            BinOp::Add => '+',
+            BinOp::Sub => '-',
+            BinOp::Mul => '*',
+            BinOp::Div => '/',
+            BinOp::Rem => '%',
+        }
+    }
+}
+
+/// Arithmetic on the old worry level, `/` and `%` round towards zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Num(i64),
+    Neg(Box<Expr>),
+    Bin(Box<Expr>, BinOp, Box<Expr>),
+}
+
+impl Expr {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, Self::parse_expr)
+    }
+
+    /// Terms joined by `+` and `-`, spaces are allowed around every token.
+    pub(super) fn parse_expr(p: &mut Parser) -> Result<Self, ParseError> {
+        Self::parse_level(p, 1)
+    }
+
+    fn parse_level(p: &mut Parser, precedence: u8) -> Result<Self, ParseError> {
+        if precedence > 2 {
+            return Self::parse_factor(p);
+        }
+
+        let mut expr = Self::parse_level(p, precedence + 1)?;
+        loop {
+            p.spaces();
+            let op = [BinOp::Add, BinOp::Sub, BinOp::Mul, BinOp::Div, BinOp::Rem]
+                .into_iter()
+                .filter(|op| op.precedence() == precedence)
+                .find(|op| p.try_literal(&op.symbol().to_string()));
+
+            match op {
+                Some(op) => {
+                    let rhs = Self::parse_level(p, precedence + 1)?;
+                    expr = Expr::Bin(Box::new(expr), op, Box::new(rhs));
+                }
+                None => return Ok(expr),
+            }
+        }
+    }
+
+    fn parse_factor(p: &mut Parser) -> Result<Self, ParseError> {
+        p.spaces();
+
+        if p.try_literal("-") {
+            return Ok(Expr::Neg(Box::new(Self::parse_factor(p)?)));
+        }
+
+        if p.try_literal("(") {
+            let expr = Self::parse_expr(p)?;
+            p.spaces();
+            p.literal(")")?;
+            return Ok(expr);
+        }
+
+        if p.try_literal("old") {
+            return Ok(Expr::Old);
+        }
+
+        match p.peek() {
+            Some(c) if c.is_ascii_digit() => p.int().map(Expr::Num),
+            _ => Err(p.error("old, number or '('")),
+        }
+    }
+
+    pub fn eval(&self, old: &Worry) -> Result<Worry> {
+        let value = match self {
+            Expr::Old => old.clone(),
+            Expr::Num(num) => Worry::from(*num),
+            Expr::Neg(expr) => expr.eval(old)?.neg(),
+            Expr::Bin(a, op, b) => {
+                let (a, b) = (a.eval(old)?, b.eval(old)?);
+                let value = match op {
+                    BinOp::Add => Ok(a.add(&b)),
+                    BinOp::Sub => Ok(a.sub(&b)),
+                    BinOp::Mul => Ok(a.mul(&b)),
+                    BinOp::Div => a.div(&b),
+                    BinOp::Rem => a.rem(&b),
+                };
+
+                value.map_err(|err| anyhow!("{} in {}", err, self))?
+            }
+        };
+
+        Ok(value)
+    }
+
+    /// [`Expr::eval`] on `i64`s, `None` if it overflows or divides by zero.
+    pub fn eval_small(&self, old: i64) -> Option<i64> {
+        match self {
+            Expr::Old => Some(old),
+            Expr::Num(num) => Some(*num),
+            Expr::Neg(expr) => expr.eval_small(old)?.checked_neg(),
+            Expr::Bin(a, op, b) => {
+                let (a, b) = (a.eval_small(old)?, b.eval_small(old)?);
+                match op {
+                    BinOp::Add => a.checked_add(b),
+                    BinOp::Sub => a.checked_sub(b),
+                    BinOp::Mul => a.checked_mul(b),
+                    BinOp::Div => a.checked_div(b),
+                    BinOp::Rem => a.checked_rem(b),
+                }
+            }
+        }
+    }
+
+    /// Uses `/` or `%`, which don't work on reduced worry levels.
+    pub fn divides(&self) -> bool {
+        match self {
+            Expr::Old | Expr::Num(_) => false,
+            Expr::Neg(expr) => expr.divides(),
+            Expr::Bin(a, op, b) => {
+                matches!(op, BinOp::Div | BinOp::Rem) || a.divides() || b.divides()
+            }
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Bin(_, op, _) => op.precedence(),
+            _ => 3,
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operand = |f: &mut std::fmt::Formatter<'_>, expr: &Expr, parens: bool| match parens {
+            true => write!(f, "({})", expr),
+            false => write!(f, "{}", expr),
+        };
+
+        match self {
+            Expr::Old => write!(f, "old"),
+            Expr::Num(num) => write!(f, "{}", num),
+            Expr::Neg(expr) => {
+                write!(f, "-")?;
+                operand(f, expr, expr.precedence() < 3)
+            }
+            Expr::Bin(a, op, b) => {
+                operand(f, a, a.precedence() < op.precedence())?;
+                write!(f, " {} ", op.symbol())?;
+                operand(f, b, b.precedence() <= op.precedence())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_eval() {
+        let eval = |input| Expr::parse(input).unwrap().eval(&Worry::from(7));
+        let worry = |input| eval(input).unwrap();
+
+        assert_eq!(worry("old * old"), Worry::from(49));
+        assert_eq!(worry("1 + 2 * old - 3"), Worry::from(12));
+        assert_eq!(worry("(1 + 2) * (old - 3)"), Worry::from(12));
+        assert_eq!(worry("-old / 2 % 2"), Worry::from(-1));
+        assert_eq!(worry("10 - 4 - 3"), Worry::from(3));
+        assert_eq!(
+            eval("old % (old - 7)").unwrap_err().to_string(),
+            "Division by zero in old % (old - 7)"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        for input in [
+            "old * 19",
+            "(old + 2) * -(old - 1)",
+            "10 - (4 - 3)",
+            "old / 2 % 5",
+        ] {
+            assert_eq!(Expr::parse(input).unwrap().to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Expr::parse("old * (3 +").unwrap_err();
+        assert_eq!((err.column(), err.expected()), (11, "old, number or '('"));
+
+        let err = Expr::parse("old ^ 2").unwrap_err();
+        assert_eq!((err.column(), err.expected()), (5, "end of line"));
+    }
+}
@@ -0,0 +1,498 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{
+    parse::{self, ParseError, Parser},
+    verbose, Solution,
+};
+
+mod cycle;
+mod expr;
+mod trace;
+mod worry;
+
+pub use expr::{BinOp, Expr};
+pub use trace::{Report, Throw, Trace};
+pub use worry::{Policy, Worry};
+
+const ROUNDS_A: u64 = 20;
+const ROUNDS_B: u64 = 10000;
+
+/// Worry levels beyond this are reported as an overflow.
+const MAX_BITS: u64 = 1 << 16;
+
+/// `new = EXPR`, the rule a monkey updates worry levels with.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Operation {
+    expr: Expr,
+}
+
+impl Operation {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, Self::parse_expr)
+    }
+
+    fn parse_expr(p: &mut Parser) -> Result<Self, ParseError> {
+        p.spaces();
+        p.literal("new")?;
+        p.spaces();
+        p.literal("=")?;
+        let expr = Expr::parse_expr(p)?;
+
+        Ok(Self { expr })
+    }
+
+    fn calculate(&self, value: &Worry) -> Result<Worry> {
+        if let Worry::Small(small) = value {
+            if let Some(result) = self.expr.eval_small(*small) {
+                return Ok(Worry::Small(result));
+            }
+        }
+
+        self.expr.eval(value)
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "new = {}", self.expr)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    id: usize,
+    items: Vec<Worry>,
+    operation: Operation,
+    test_div: i64,
+    on_true: usize,
+    on_false: usize,
+    inspect_count: usize,
+}
+
+impl Monkey {
+    pub fn new(
+        id: usize,
+        items: Vec<i64>,
+        operation: Operation,
+        test_div: i64,
+        on_true: usize,
+        on_false: usize,
+    ) -> Self {
+        Self {
+            id,
+            items: items.into_iter().map(Worry::from).collect(),
+            operation,
+            test_div,
+            on_false,
+            on_true,
+            inspect_count: 0,
+        }
+    }
+
+    fn process(&self, value: &Worry, reduce: Reduce) -> Result<(Worry, usize)> {
+        let value = self.operation.calculate(value)?;
+        let value = match reduce {
+            Reduce::Divide(k) => value.div_floor(k),
+            Reduce::Modulo(m) => Worry::from(value.rem_euclid(m)),
+            Reduce::Exact => value,
+        };
+
+        if value.bits() > MAX_BITS {
+            bail!(
+                "Worry level of an item thrown by monkey {} overflows {} bits",
+                self.id,
+                MAX_BITS
+            );
+        }
+
+        let target = match value.is_multiple_of(self.test_div) {
+            true => self.on_true,
+            false => self.on_false,
+        };
+
+        Ok((value, target))
+    }
+
+    fn take(&mut self) -> Vec<Worry> {
+        let mut next = vec![];
+        std::mem::swap(&mut self.items, &mut next);
+
+        next
+    }
+
+    fn add(&mut self, value: Worry) {
+        self.items.push(value);
+    }
+
+    fn inspect(&mut self) {
+        self.inspect_count += 1;
+    }
+}
+
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let id = &self.id;
+        let hold = self
+            .items
+            .iter()
+            .map(|item| format!("{item}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "M {id} [{hold}]")
+    }
+}
+
+/// [`Policy`] with the modulus worked out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reduce {
+    Divide(i64),
+    Modulo(i64),
+    Exact,
+}
+
+#[derive(Clone)]
+pub struct Game {
+    monkeys: Vec<Monkey>,
+    turn: usize,
+    reduce: Reduce,
+    trace: Option<Trace>,
+}
+
+impl Game {
+    /// Orders the monkeys by id, which have to be `0` to `n - 1`, and checks
+    /// that every monkey throws to one of them.
+    pub fn new(mut monkeys: Vec<Monkey>) -> Result<Self> {
+        monkeys.sort_by_key(|m| m.id);
+
+        for (idx, monkey) in monkeys.iter().enumerate() {
+            if monkey.id != idx {
+                bail!(
+                    "Monkeys are numbered 0 to {}, found {}",
+                    monkeys.len() - 1,
+                    monkey.id
+                );
+            }
+
+            for target in [monkey.on_true, monkey.on_false] {
+                if target >= monkeys.len() {
+                    bail!("Monkey {} throws to missing monkey {}", monkey.id, target);
+                }
+            }
+        }
+
+        Ok(Self {
+            monkeys,
+            turn: 0,
+            reduce: Reduce::Exact,
+            trace: None,
+        })
+    }
+
+    /// Worry levels are kept exact until a policy is set.
+    pub fn with_policy(mut self, policy: Policy) -> Result<Self> {
+        self.reduce = match policy {
+            Policy::Divide(k) if k <= 0 => bail!("Can't divide worry levels by {}", k),
+            Policy::Divide(k) => Reduce::Divide(k),
+            Policy::Modulo => {
+                let modulus = self
+                    .monkeys
+                    .iter()
+                    .try_fold(1, |lcm, m| worry::lcm(lcm, m.test_div))
+                    .ok_or_else(|| anyhow!("Common multiple of the test divisors overflows"))?;
+
+                if let Some(monkey) = self.monkeys.iter().find(|m| m.operation.expr.divides()) {
+                    bail!(
+                        "Monkey {} divides, its worry levels can't be reduced modulo {}",
+                        monkey.id,
+                        modulus
+                    );
+                }
+
+                Reduce::Modulo(modulus)
+            }
+            Policy::Exact => Reduce::Exact,
+        };
+
+        Ok(self)
+    }
+
+    pub fn turn(&mut self) -> Result<()> {
+        self.turn += 1;
+
+        for i in 0..self.monkeys.len() {
+            let items = self.monkeys[i].take();
+            for item in items {
+                self.monkeys[i].inspect();
+                let (next, target) = self.monkeys[i].process(&item, self.reduce)?;
+
+                if let Some(trace) = &mut self.trace {
+                    trace.throws.push(Throw {
+                        round: self.turn,
+                        from: i,
+                        to: target,
+                        before: item,
+                        after: next.clone(),
+                    });
+                }
+
+                self.monkeys[target].add(next);
+            }
+        }
+
+        if let Some(trace) = &mut self.trace {
+            trace
+                .counts
+                .push(self.monkeys.iter().map(|m| m.inspect_count).collect());
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Turn {}", self.turn)?;
+        for m in &self.monkeys {
+            writeln!(f, "{m}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub mod input {
+    use super::Monkey;
+    use super::Operation;
+    use crate::parse::{ParseError, Parser};
+
+    const FIELDS: [&str; 5] = [
+        "Starting items:",
+        "Operation:",
+        "Test: divisible by",
+        "If true: throw to monkey",
+        "If false: throw to monkey",
+    ];
+
+    #[derive(Default)]
+    struct Fields {
+        items: Option<Vec<i64>>,
+        operation: Option<Operation>,
+        test_div: Option<i64>,
+        on_true: Option<usize>,
+        on_false: Option<usize>,
+    }
+
+    /// `Monkey N:` followed by one field per line in any order, every field
+    /// given exactly once.
+    pub fn parse_input(input: &str) -> Result<Monkey, ParseError> {
+        Parser::parse(input, |p| {
+            p.literal("Monkey ")?;
+            let id = p.int()?;
+            p.literal(":")?;
+
+            let mut fields = Fields::default();
+            p.whitespace();
+
+            while !p.is_empty() {
+                let start = p.offset();
+                let field = FIELDS
+                    .iter()
+                    .position(|label| p.try_literal(label))
+                    .ok_or_else(|| p.error("monkey field"))?;
+
+                let duplicate = match field {
+                    0 => fields.items.replace(parse_items(p)?).is_some(),
+                    1 => fields
+                        .operation
+                        .replace(Operation::parse_expr(p)?)
+                        .is_some(),
+                    2 => fields.test_div.replace(parse_divisor(p)?).is_some(),
+                    3 => fields.on_true.replace(parse_target(p)?).is_some(),
+                    _ => fields.on_false.replace(parse_target(p)?).is_some(),
+                };
+
+                if duplicate {
+                    let expected = format!("a single {:?} line", FIELDS[field]);
+                    return Err(p.error_at(expected, start));
+                }
+
+                if !p.is_empty() {
+                    p.literal("\n")?;
+                }
+                p.whitespace();
+            }
+
+            let missing = |label| p.error(format!("{:?}", label));
+            Ok(Monkey::new(
+                id,
+                fields.items.ok_or_else(|| missing(FIELDS[0]))?,
+                fields.operation.ok_or_else(|| missing(FIELDS[1]))?,
+                fields.test_div.ok_or_else(|| missing(FIELDS[2]))?,
+                fields.on_true.ok_or_else(|| missing(FIELDS[3]))?,
+                fields.on_false.ok_or_else(|| missing(FIELDS[4]))?,
+            ))
+        })
+    }
+
+    /// Comma separated, may be empty.
+    fn parse_items(p: &mut Parser) -> Result<Vec<i64>, ParseError> {
+        p.spaces();
+
+        match p.peek() {
+            None | Some('\n') => Ok(vec![]),
+            _ => p.separated(",", |p| {
+                p.spaces();
+                let item = p.int()?;
+                p.spaces();
+                Ok(item)
+            }),
+        }
+    }
+
+    fn parse_divisor(p: &mut Parser) -> Result<i64, ParseError> {
+        p.spaces();
+        let start = p.offset();
+
+        match p.int()? {
+            divisor if divisor > 0 => Ok(divisor),
+            _ => Err(p.error_at("positive number", start)),
+        }
+    }
+
+    fn parse_target(p: &mut Parser) -> Result<usize, ParseError> {
+        p.spaces();
+        let target = p.int()?;
+        p.spaces();
+
+        Ok(target)
+    }
+}
+
+use input::parse_input;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Game;
+    type A = u128;
+    type B = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let monkeys = parse::blocks(input, parse_input)?;
+
+        Game::new(monkeys)
+    }
+
+    fn part_a(game: &Self::Input) -> Result<u128> {
+        let mut game_a = game.clone().with_policy(Policy::Divide(3))?;
+        game_a.run_rounds(ROUNDS_A)?;
+
+        let report = game_a.report(2)?;
+        verbose!("{}", report);
+
+        Ok(report.business)
+    }
+
+    fn part_b(game: &Self::Input) -> Result<u128> {
+        let mut game_b = game.clone().with_policy(Policy::Modulo)?;
+        game_b.run_rounds(ROUNDS_B)?;
+
+        let report = game_b.report(2)?;
+        verbose!("{}", report);
+
+        Ok(report.business)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn parse_op1() {
+        let input = "new = old * 19";
+        let op = Operation::parse(input).unwrap();
+        let expected = Operation {
+            expr: Expr::Bin(Box::new(Expr::Old), BinOp::Mul, Box::new(Expr::Num(19))),
+        };
+
+        assert_eq!(op, expected);
+        assert_eq!(op.to_string(), input);
+    }
+
+    #[test]
+    fn test_field_order() {
+        let input = "Monkey 1:\n  If false: throw to monkey 0\n  Test: divisible by 5\n  \
+            Operation: new = (old + 3) % 7\n  Starting items:\n  If true: throw to monkey 1\n";
+        let monkey = parse_input(input).unwrap();
+
+        assert_eq!((monkey.id, monkey.on_true, monkey.on_false), (1, 1, 0));
+        assert_eq!((monkey.test_div, monkey.items.len()), (5, 0));
+        assert_eq!(
+            monkey.operation.calculate(&Worry::from(6)).unwrap(),
+            Worry::from(2)
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let err =
+            parse_input("Monkey 0:\n  Test: divisible by 5\n  Test: divisible by 3").unwrap_err();
+        assert_eq!((err.line(), err.column()), (3, 3));
+
+        let err = parse_input("Monkey 0:\n  Test: divisible by 5").unwrap_err();
+        assert_eq!(err.expected(), "\"Starting items:\"");
+
+        let input = include_str!("../../examples/day11.txt").replace("monkey 3", "monkey 4");
+        let err = Day11::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "Monkey 0 throws to missing monkey 4");
+    }
+
+    #[test]
+    fn test_policies() {
+        let game = Day11::parse(include_str!("../../examples/day11.txt")).unwrap();
+        let run = |policy, turns| -> Result<u128> {
+            let mut game = game.clone().with_policy(policy)?;
+            for _ in 0..turns {
+                game.turn()?;
+            }
+
+            Ok(game.report(2)?.business)
+        };
+
+        assert_eq!(run(Policy::Divide(3), 20).unwrap(), 10605);
+        assert_eq!(
+            run(Policy::Exact, 8).unwrap(),
+            run(Policy::Modulo, 8).unwrap()
+        );
+        assert!(run(Policy::Divide(0), 1).is_err());
+
+        let monkey = "Monkey 0:\nStarting items: 2\nOperation: new = old * old\n\
+            Test: divisible by 2\nIf true: throw to monkey 0\nIf false: throw to monkey 0";
+        let mut game = Day11::parse(monkey).unwrap();
+        for _ in 0..15 {
+            game.turn().unwrap();
+        }
+        assert_eq!(
+            game.monkeys[0].items,
+            [Worry::Big(BigInt::from(2).pow(1 << 15))]
+        );
+        assert_eq!(
+            game.turn().unwrap_err().to_string(),
+            "Worry level of an item thrown by monkey 0 overflows 65536 bits"
+        );
+
+        let input = include_str!("../../examples/day11.txt").replace("old * 19", "old / 2");
+        let game = Day11::parse(&input).unwrap().with_policy(Policy::Modulo);
+        assert_eq!(
+            game.err().unwrap().to_string(),
+            "Monkey 0 divides, its worry levels can't be reduced modulo 96577"
+        );
+    }
+}
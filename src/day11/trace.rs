@@ -0,0 +1,188 @@
+use std::fmt::{Display, Write};
+
+use anyhow::{anyhow, bail, Result};
+
+use super::{Game, Worry};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Throw {
+    pub round: usize,
+    pub from: usize,
+    pub to: usize,
+    pub before: Worry,
+    pub after: Worry,
+}
+
+/// Every throw, and the inspections of every monkey after each round.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub throws: Vec<Throw>,
+    pub counts: Vec<Vec<usize>>,
+}
+
+impl Trace {
+    pub fn throws_csv(&self) -> String {
+        let mut out = String::from("round,from,to,before,after\n");
+        for throw in &self.throws {
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{}",
+                throw.round, throw.from, throw.to, throw.before, throw.after
+            );
+        }
+
+        out
+    }
+
+    /// Inspections so far, one line per round and monkey.
+    pub fn counts_csv(&self) -> String {
+        let mut out = String::from("round,monkey,inspections\n");
+        for (round, counts) in self.counts.iter().enumerate() {
+            for (monkey, count) in counts.iter().enumerate() {
+                let _ = writeln!(out, "{},{},{}", round + 1, monkey, count);
+            }
+        }
+
+        out
+    }
+
+    pub fn throws_json(&self) -> String {
+        let throws: Vec<_> = self
+            .throws
+            .iter()
+            .map(|throw| {
+                format!(
+                    "{{\"round\":{},\"from\":{},\"to\":{},\"before\":{},\"after\":{}}}",
+                    throw.round, throw.from, throw.to, throw.before, throw.after
+                )
+            })
+            .collect();
+
+        format!("[{}]\n", throws.join(","))
+    }
+
+    /// Inspections so far, one array per round.
+    pub fn counts_json(&self) -> String {
+        let rounds: Vec<_> = self
+            .counts
+            .iter()
+            .map(|counts| {
+                let counts: Vec<_> = counts.iter().map(|count| count.to_string()).collect();
+                format!("[{}]", counts.join(","))
+            })
+            .collect();
+
+        format!("[{}]\n", rounds.join(","))
+    }
+}
+
+/// The `k` monkeys inspecting the most items, their product is the level of
+/// monkey business.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Monkey and its inspections, most first.
+    pub top: Vec<(usize, usize)>,
+    pub business: u128,
+}
+
+impl Game {
+    /// Records every throw from now on, rounds are then always simulated one
+    /// by one.
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(Trace::default());
+        self
+    }
+
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
+    pub fn report(&self, k: usize) -> Result<Report> {
+        if k > self.monkeys.len() {
+            bail!("Can't pick {} of {} monkeys", k, self.monkeys.len());
+        }
+
+        let mut top: Vec<_> = self
+            .monkeys
+            .iter()
+            .map(|m| (m.id, m.inspect_count))
+            .collect();
+        top.sort_by_key(|(id, count)| (std::cmp::Reverse(*count), *id));
+        top.truncate(k);
+
+        let business = top
+            .iter()
+            .try_fold(1u128, |product, (_, count)| {
+                product.checked_mul(*count as u128)
+            })
+            .ok_or_else(|| anyhow!("Monkey business of the top {} monkeys overflows", k))?;
+
+        Ok(Report { top, business })
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (monkey, count) in &self.top {
+            writeln!(f, "Monkey {} inspected items {} times.", monkey, count)?;
+        }
+
+        write!(f, "Monkey business: {}", self.business)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        day11::{Day11, Policy},
+        Solution,
+    };
+
+    #[test]
+    fn test_trace() {
+        let game = Day11::parse(include_str!("../../examples/day11.txt")).unwrap();
+        let mut game = game.with_policy(Policy::Divide(3)).unwrap().with_trace();
+        game.run_rounds(20).unwrap();
+
+        let trace = game.trace().unwrap();
+        assert_eq!(
+            trace.throws[0],
+            Throw {
+                round: 1,
+                from: 0,
+                to: 3,
+                before: Worry::from(79),
+                after: Worry::from(500),
+            }
+        );
+        assert_eq!(trace.counts.len(), 20);
+        assert_eq!(trace.counts[19], [101, 95, 7, 105]);
+        assert_eq!(
+            trace.counts[0].iter().sum::<usize>(),
+            trace.throws.iter().filter(|t| t.round == 1).count()
+        );
+
+        assert!(trace
+            .throws_csv()
+            .starts_with("round,from,to,before,after\n1,0,3,79,500\n"));
+        assert!(trace.counts_csv().ends_with("20,2,7\n20,3,105\n"));
+        assert!(trace
+            .throws_json()
+            .starts_with("[{\"round\":1,\"from\":0,\"to\":3,\"before\":79,\"after\":500},"));
+        assert!(trace.counts_json().ends_with(",[101,95,7,105]]\n"));
+    }
+
+    #[test]
+    fn test_report() {
+        let game = Day11::parse(include_str!("../../examples/day11.txt")).unwrap();
+        let mut game = game.with_policy(Policy::Divide(3)).unwrap();
+        game.run_rounds(20).unwrap();
+
+        let report = game.report(3).unwrap();
+        assert_eq!(report.top, [(3, 105), (0, 101), (1, 95)]);
+        assert_eq!(report.business, 105 * 101 * 95);
+        assert_eq!(game.report(2).unwrap().business, 10605);
+        assert!(game.report(5).is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, bail, Result};
+use num_bigint::{BigInt, Sign};
+
+/// Worry level of an item, an `i64` until it overflows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Worry {
+    Small(i64),
+    Big(BigInt),
+}
+
+impl Worry {
+    /// Smallest representation of `big`.
+    fn normalize(big: BigInt) -> Self {
+        match i64::try_from(&big) {
+            Ok(small) => Worry::Small(small),
+            Err(_) => Worry::Big(big),
+        }
+    }
+
+    fn big(&self) -> BigInt {
+        match self {
+            Worry::Small(small) => BigInt::from(*small),
+            Worry::Big(big) => big.clone(),
+        }
+    }
+
+    /// `small` on two `i64`s unless it overflows, `big` otherwise.
+    fn combine<S, B>(&self, other: &Worry, small: S, big: B) -> Self
+    where
+        S: Fn(i64, i64) -> Option<i64>,
+        B: Fn(BigInt, BigInt) -> BigInt,
+    {
+        if let (Worry::Small(a), Worry::Small(b)) = (self, other) {
+            if let Some(result) = small(*a, *b) {
+                return Worry::Small(result);
+            }
+        }
+
+        Self::normalize(big(self.big(), other.big()))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Worry::Small(0)
+    }
+
+    pub fn add(&self, other: &Worry) -> Self {
+        self.combine(other, i64::checked_add, |a, b| a + b)
+    }
+
+    pub fn sub(&self, other: &Worry) -> Self {
+        self.combine(other, i64::checked_sub, |a, b| a - b)
+    }
+
+    pub fn mul(&self, other: &Worry) -> Self {
+        self.combine(other, i64::checked_mul, |a, b| a * b)
+    }
+
+    pub fn neg(&self) -> Self {
+        Worry::Small(0).sub(self)
+    }
+
+    /// Rounds towards zero.
+    pub fn div(&self, other: &Worry) -> Result<Self> {
+        if other.is_zero() {
+            bail!("Division by zero");
+        }
+
+        Ok(self.combine(other, i64::checked_div, |a, b| a / b))
+    }
+
+    /// Takes the sign of `self`.
+    pub fn rem(&self, other: &Worry) -> Result<Self> {
+        if other.is_zero() {
+            bail!("Division by zero");
+        }
+
+        Ok(self.combine(other, i64::checked_rem, |a, b| a % b))
+    }
+
+    /// Rounds down, `divisor` has to be positive.
+    pub fn div_floor(&self, divisor: i64) -> Self {
+        match self {
+            Worry::Small(small) => Worry::Small(small.div_euclid(divisor)),
+            Worry::Big(big) => {
+                let quotient = big / divisor;
+                match big.sign() == Sign::Minus && big % divisor != BigInt::from(0) {
+                    true => Self::normalize(quotient - 1),
+                    false => Self::normalize(quotient),
+                }
+            }
+        }
+    }
+
+    /// Remainder in `0..modulus`, `modulus` has to be positive.
+    pub fn rem_euclid(&self, modulus: i64) -> i64 {
+        match self {
+            Worry::Small(small) => small.rem_euclid(modulus),
+            Worry::Big(big) => {
+                let rem = i64::try_from(big % modulus).expect("Remainder fits the modulus");
+                rem.rem_euclid(modulus)
+            }
+        }
+    }
+
+    pub fn is_multiple_of(&self, divisor: i64) -> bool {
+        self.rem_euclid(divisor) == 0
+    }
+
+    pub fn bits(&self) -> u64 {
+        match self {
+            Worry::Small(small) => u64::from(64 - small.unsigned_abs().leading_zeros()),
+            Worry::Big(big) => big.bits(),
+        }
+    }
+}
+
+impl From<i64> for Worry {
+    fn from(value: i64) -> Self {
+        Worry::Small(value)
+    }
+}
+
+impl Display for Worry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Worry::Small(small) => write!(f, "{}", small),
+            Worry::Big(big) => write!(f, "{}", big),
+        }
+    }
+}
+
+/// How worry levels are kept manageable after every inspection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Divides by `k`, rounding down.
+    Divide(i64),
+    /// Reduces modulo the least common multiple of the test divisors, which
+    /// keeps every test exact.
+    Modulo,
+    /// No reduction at all.
+    Exact,
+}
+
+impl Policy {
+    /// `divide:K`, `modulo` or `exact`.
+    pub fn parse(input: &str) -> Result<Self> {
+        match input.split_once(':') {
+            Some(("divide", k)) => k
+                .parse()
+                .map(Policy::Divide)
+                .map_err(|_| anyhow!("Invalid divisor: {}", k)),
+            None if input == "modulo" => Ok(Policy::Modulo),
+            None if input == "exact" => Ok(Policy::Exact),
+            _ => bail!("Invalid policy: {}", input),
+        }
+    }
+}
+
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    (a / x).checked_mul(b)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_overflow() {
+        let max = Worry::from(i64::MAX);
+        let big = max.mul(&max);
+
+        assert!(matches!(big, Worry::Big(_)));
+        assert_eq!(big.bits(), 126);
+        assert_eq!(big.div(&max).unwrap(), max);
+        assert_eq!(big.sub(&big), Worry::Small(0));
+        assert_eq!(
+            Worry::from(i64::MIN).neg().to_string(),
+            "9223372036854775808"
+        );
+        assert_eq!(
+            Worry::from(i64::MIN).div(&Worry::from(-1)).unwrap().bits(),
+            64
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        let big = Worry::from(i64::MIN).mul(&Worry::from(3));
+
+        assert_eq!(Worry::from(-7).div_floor(2), Worry::Small(-4));
+        assert_eq!(big.div_floor(3), Worry::Small(i64::MIN));
+        assert_eq!(
+            big.sub(&Worry::from(1)).div_floor(3),
+            Worry::Small(i64::MIN).sub(&Worry::from(1))
+        );
+        assert_eq!(
+            big.rem_euclid(7),
+            Worry::from(i64::MIN).rem_euclid(7) * 3 % 7
+        );
+        assert!(big.is_multiple_of(3));
+        assert!(Worry::from(1).rem(&Worry::from(0)).is_err());
+        assert_eq!(Policy::parse("divide:3").unwrap(), Policy::Divide(3));
+        assert!(Policy::parse("divide").is_err());
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(i64::MAX, 2), None);
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    grid::{Coord, Grid},
+    parse::ParseError,
+    search, Solution,
+};
+
+type HeightMap = Grid<i32>;
+
+#[derive(Debug)]
+pub struct Input {
+    height_map: HeightMap,
+    start_point: Coord,
+    target: Coord,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let expected = "height 'a'..='z', 'S' or 'E'";
+        let map = Grid::parse(input, expected, |ch| {
+            matches!(ch, 'a'..='z' | 'S' | 'E').then_some(ch)
+        })?;
+
+        let find = |ch, expected| {
+            map.iter()
+                .find(|(_, c)| **c == ch)
+                .map(|(coord, _)| coord)
+                .ok_or_else(|| ParseError::new(expected, input, input.len()))
+        };
+
+        let start_point = find('S', "start point 'S'")?;
+        let target = find('E', "target 'E'")?;
+
+        let height_map = map.map(|ch| match ch {
+            'S' => Input::height('a'),
+            'E' => Input::height('z'),
+            ch => Input::height(*ch),
+        });
+
+        Ok(Self {
+            height_map,
+            start_point,
+            target,
+        })
+    }
+
+    fn height(ch: char) -> i32 {
+        ch as i32 - 'a' as i32
+    }
+}
+
+impl Input {
+    /// Steps may climb at most one level.
+    fn neighbors(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        let height = self.height_map[coord];
+        self.height_map
+            .neighbors4(coord)
+            .filter(move |next| height + 1 >= self.height_map[*next])
+    }
+
+    /// Shortest path from the closest of `starts` to the target.
+    pub fn path<I>(&self, starts: I) -> Option<Vec<Coord>>
+    where
+        I: IntoIterator<Item = Coord>,
+    {
+        let reached = search::bfs_multi(
+            starts,
+            |coord| self.neighbors(*coord),
+            |coord| *coord == self.target,
+        );
+
+        reached.path(reached.found()?)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Input;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Input::parse(input)?)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<usize> {
+        let path = input
+            .path([input.start_point])
+            .ok_or_else(|| anyhow!("Task a Not solved"))?;
+
+        Ok(path.len() - 1)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<usize> {
+        let start_points =
+            input
+                .height_map
+                .iter()
+                .filter_map(|(point, height)| if height == &0 { Some(point) } else { None });
+
+        let path = input
+            .path(start_points)
+            .ok_or_else(|| anyhow!("Task b Not solved"))?;
+
+        Ok(path.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let input = Day12::parse(include_str!("../../examples/day12.txt")).unwrap();
+        let path = input.path([input.start_point]).unwrap();
+
+        assert_eq!(path.len(), 32);
+        assert_eq!((path[0], path[31]), (input.start_point, input.target));
+        assert!(path
+            .windows(2)
+            .all(|step| input.neighbors(step[0]).any(|next| next == step[1])));
+    }
+}
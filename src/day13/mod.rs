@@ -0,0 +1,288 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    parse::{self, ParseError, Parser},
+    Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Item {
+    Number(i32),
+    List(Vec<Item>),
+}
+
+impl Item {
+    #[cfg(test)]
+    pub fn empty_list() -> Self {
+        Self::List(vec![])
+    }
+
+    pub fn num(num: i32) -> Self {
+        Self::Number(num)
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, Item::parse_list)
+    }
+
+    fn parse_list(p: &mut Parser) -> Result<Self, ParseError> {
+        p.literal("[")?;
+
+        let mut list = vec![];
+        if p.try_literal("]") {
+            return Ok(Item::List(list));
+        }
+
+        loop {
+            list.push(Item::parse_item(p)?);
+
+            if p.try_literal("]") {
+                return Ok(Item::List(list));
+            }
+
+            if !p.try_literal(",") {
+                return Err(p.error("',' or ']'"));
+            }
+        }
+    }
+
+    fn parse_item(p: &mut Parser) -> Result<Self, ParseError> {
+        match p.peek() {
+            Some('[') => Item::parse_list(p),
+            Some(c) if c.is_ascii_digit() => p.int().map(Item::Number),
+            _ => Err(p.error("list or number")),
+        }
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Item::Number(a), Item::Number(b)) => a.cmp(b),
+            (Item::List(a), Item::List(b)) => cmp_list(a, b),
+            (Item::Number(a), Item::List(b)) => cmp_list(&[Item::num(*a)], b),
+            (Item::List(a), Item::Number(b)) => cmp_list(a, &[Item::num(*b)]),
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn cmp_list(a: &[Item], b: &[Item]) -> Ordering {
+    for (idx, ai) in a.iter().enumerate() {
+        let bi = b.get(idx);
+
+        match bi {
+            Some(bi) => match ai.partial_cmp(bi) {
+                Some(Ordering::Less) => return Ordering::Less,
+                Some(Ordering::Greater) => return Ordering::Greater,
+                _ => {}
+            },
+            None => return Ordering::Greater,
+        }
+    }
+
+    if a.len() == b.len() {
+        Ordering::Equal
+    } else {
+        Ordering::Less
+    }
+}
+
+#[derive(Debug)]
+pub struct Pair {
+    a: Item,
+    b: Item,
+}
+
+impl Pair {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let missing = || ParseError::new("packet", input, input.len());
+        let a = lines.next().ok_or_else(missing)?;
+        let b = lines.next().ok_or_else(missing)?;
+
+        Ok(Self {
+            a: Item::parse(a)?,
+            b: Item::parse(b).map_err(|err| err.shift_lines(1))?,
+        })
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Pair>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::blocks(input, Pair::parse)?)
+    }
+
+    fn part_a(pairs: &Self::Input) -> Result<usize> {
+        let task_a = pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, p)| if p.a < p.b { Some(idx + 1) } else { None })
+            .sum();
+
+        Ok(task_a)
+    }
+
+    fn part_b(pairs: &Self::Input) -> Result<usize> {
+        let mut total = pairs.iter().flat_map(|p| [&p.a, &p.b]).collect::<Vec<_>>();
+
+        let sample_a = Item::parse("[[2]]")?;
+        let sample_b = Item::parse("[[6]]")?;
+
+        total.push(&sample_a);
+        total.push(&sample_b);
+        total.sort();
+
+        let key_a = total
+            .iter()
+            .position(|i| *i == &sample_a)
+            .map(|idx| idx + 1)
+            .ok_or_else(|| anyhow!("NOT FOUND A"))?;
+
+        let key_b = total
+            .iter()
+            .position(|i| *i == &sample_b)
+            .map(|idx| idx + 1)
+            .ok_or_else(|| anyhow!("NOT FOUND B"))?;
+
+        Ok(key_a * key_b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty() {
+        let input = "[]";
+        let result = Item::parse(input).unwrap();
+        let expected = Item::List(vec![]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_empty_inner() {
+        let input = "[[]]";
+        let result = Item::parse(input).unwrap();
+        let expected = Item::List(vec![Item::List(vec![])]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_empty_inner2() {
+        let input = "[[[]]]";
+        let result = Item::parse(input).unwrap();
+        let expected = Item::List(vec![Item::List(vec![Item::List(vec![])])]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_comma() {
+        let input = "[[],[]]";
+        let result = Item::parse(input).unwrap();
+        let expected = Item::List(vec![Item::List(vec![]), Item::List(vec![])]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_comma2() {
+        let input = "[[[]],[],[]]";
+        let result = Item::parse(input).unwrap();
+        let expected = Item::List(vec![
+            Item::List(vec![Item::empty_list()]),
+            Item::empty_list(),
+            Item::empty_list(),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_number() {
+        let input = "[123,10,[11]]";
+        let result = Item::parse(input).unwrap();
+        let expected = Item::List(vec![
+            Item::Number(123),
+            Item::Number(10),
+            Item::List(vec![Item::Number(11)]),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_cmp() {
+        let a = Item::Number(10);
+        let b = Item::Number(5);
+
+        assert!(a > b);
+    }
+
+    #[test]
+    fn test_cmp_list() {
+        let a = Item::parse("[5]").unwrap();
+        let b = Item::parse("[10]").unwrap();
+
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_cmp_list2() {
+        let a = Item::parse("[5,6]").unwrap();
+        let b = Item::parse("[5,7]").unwrap();
+
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_cmp_list3() {
+        let a = Item::parse("[5,6]").unwrap();
+        let b = Item::parse("[5]").unwrap();
+
+        assert!(a > b);
+    }
+
+    #[test]
+    fn test_cmp_list4() {
+        let a = Item::parse("[5,6]").unwrap();
+        let b = Item::parse("[5,6,1]").unwrap();
+
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_cmp_list_with_num() {
+        let a = Item::parse("[[1],2]").unwrap();
+        let b = Item::parse("[1,[3]]").unwrap();
+
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let err = Item::parse("[1,[2,x]]").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 7));
+        assert_eq!(err.expected(), "list or number");
+    }
+
+    #[test]
+    fn test_parse_error_unclosed() {
+        let err = Item::parse("[1,[2]").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 7));
+        assert_eq!(err.found(), "end of input");
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::{
+    geom::Dir8,
+    grid::{Pos, SparseGrid},
+    parse::{self, ParseError},
+    Solution,
+};
+
+#[derive(Debug)]
+pub enum Segment {
+    Horizontal { xa: i32, xb: i32, y: i32 },
+    Vertical { x: i32, ya: i32, yb: i32 },
+}
+
+impl Segment {
+    pub fn from_points(a: &Pos, b: &Pos) -> Option<Self> {
+        if a.y == b.y {
+            let xmin = a.x.min(b.x);
+            let xmax = a.x.max(b.x);
+
+            Some(Self::Horizontal {
+                xa: xmin,
+                xb: xmax,
+                y: a.y,
+            })
+        } else if a.x == b.x {
+            let ymin = a.y.min(b.y);
+            let ymax = a.y.max(b.y);
+
+            Some(Self::Vertical {
+                x: a.x,
+                ya: ymin,
+                yb: ymax,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn points(&self) -> impl Iterator<Item = Pos> + '_ {
+        let points: Vec<Pos> = match self {
+            Segment::Horizontal { xa, xb, y } => (*xa..=*xb).map(|x| Pos::new(x, *y)).collect(),
+            Segment::Vertical { x, ya, yb } => (*ya..=*yb).map(|y| Pos::new(*x, y)).collect(),
+        };
+
+        points.into_iter()
+    }
+}
+
+pub fn parse_point(input: &str) -> Result<Pos, ParseError> {
+    let comma_index = input
+        .find(',')
+        .ok_or_else(|| ParseError::new("','", input, input.len()))?;
+
+    let x = input[..comma_index]
+        .parse::<i32>()
+        .map_err(|_| ParseError::new("number", input, 0))?;
+
+    let y = input[comma_index + 1..]
+        .parse::<i32>()
+        .map_err(|_| ParseError::new("number", input, comma_index + 1))?;
+
+    Ok(Pos::new(x, y))
+}
+
+pub fn parse_segments(input: &str) -> Result<Vec<Segment>, ParseError> {
+    let mut offset = 0;
+    let mut parts = vec![];
+
+    for part in input.split(" -> ") {
+        let point = parse_point(part).map_err(|err| err.shift_columns(offset))?;
+        parts.push((offset, point));
+        offset += part.len() + " -> ".len();
+    }
+
+    parts
+        .windows(2)
+        .map(|w| {
+            Segment::from_points(&w[0].1, &w[1].1)
+                .ok_or_else(|| ParseError::new("horizontal or vertical line", input, w[1].0))
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    start_point: Pos,
+    depth: i32,
+    cells: SparseGrid<Cell>,
+    has_floor: bool,
+}
+
+impl Grid {
+    pub fn new(point: &Pos, has_floor: bool) -> Self {
+        let cells = SparseGrid::new();
+
+        Self {
+            cells,
+            start_point: *point,
+            depth: point.y,
+            has_floor,
+        }
+    }
+
+    pub fn add_segment(&mut self, segment: &Segment) {
+        for point in segment.points() {
+            self.depth = self.depth.max(point.y);
+            self.add_solid(point);
+        }
+    }
+
+    fn add_solid(&mut self, point: Pos) {
+        self.cells.insert(point, Cell::Solid);
+    }
+
+    fn add_sand(&mut self, point: Pos) {
+        self.cells.insert(point, Cell::Sand);
+    }
+
+    pub fn drop_sand(&mut self) -> DropResult {
+        let mut sand = self.start_point;
+
+        if !self.is_empty(&sand) {
+            return DropResult::Blocked;
+        }
+
+        loop {
+            if sand.y > self.depth + 3 {
+                return DropResult::Fall;
+            }
+
+            let next = [Dir8::Down, Dir8::DownLeft, Dir8::DownRight]
+                .iter()
+                .map(|dir| sand + dir.offset())
+                .find(|p| self.is_empty(p));
+
+            match next {
+                Some(p) => sand = p,
+                None => {
+                    self.add_sand(sand);
+                    return DropResult::Stay;
+                }
+            }
+        }
+    }
+
+    fn is_empty(&self, p: &Pos) -> bool {
+        if self.has_floor && p.y >= self.depth + 2 {
+            return false;
+        }
+
+        !self.cells.contains(*p)
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+#[derive(Debug)]
+pub enum Cell {
+    Solid,
+    Sand,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell::Solid => write!(f, "#"),
+            Cell::Sand => write!(f, "o"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DropResult {
+    Stay,
+    Fall,
+    Blocked,
+}
+
+fn grid(segments: &[Segment], has_floor: bool) -> Grid {
+    let start_point = Pos::new(500, 0);
+    let mut grid = Grid::new(&start_point, has_floor);
+
+    for segment in segments {
+        grid.add_segment(segment);
+    }
+
+    grid
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Segment>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let segments = parse::lines(input, parse_segments)?;
+        Ok(segments.into_iter().flatten().collect())
+    }
+
+    fn part_a(segments: &Self::Input) -> Result<usize> {
+        let mut grid_a = grid(segments, false);
+
+        let result_a = std::iter::repeat(())
+            .map(|_| grid_a.drop_sand())
+            .take_while(|r| *r == DropResult::Stay)
+            .count();
+
+        Ok(result_a)
+    }
+
+    fn part_b(segments: &Self::Input) -> Result<usize> {
+        let mut grid_b = grid(segments, true);
+
+        let result_b = std::iter::repeat(())
+            .map(|_| grid_b.drop_sand())
+            .take_while(|r| *r != DropResult::Blocked)
+            .count();
+
+        Ok(result_b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../examples/day14.txt");
+
+    #[test]
+    fn test_parse() {
+        let segments = parse_segments("498,4 -> 498,6 -> 496,6").unwrap();
+        assert!(matches!(
+            segments[..],
+            [
+                Segment::Vertical {
+                    x: 498,
+                    ya: 4,
+                    yb: 6
+                },
+                Segment::Horizontal {
+                    xa: 496,
+                    xb: 498,
+                    y: 6
+                },
+            ]
+        ));
+
+        let err = parse_segments("498,4 -> 497,6").unwrap_err();
+        assert_eq!(
+            (err.column(), err.expected()),
+            (10, "horizontal or vertical line")
+        );
+
+        let err = parse_segments("498,4 -> 498;6").unwrap_err();
+        assert_eq!((err.column(), err.expected()), (15, "','"));
+    }
+
+    #[test]
+    fn test_drop_sand() {
+        let segments = Day14::parse(EXAMPLE).unwrap();
+        let mut grid_a = grid(&segments, false);
+
+        for _ in 0..5 {
+            assert_eq!(grid_a.drop_sand(), DropResult::Stay);
+        }
+        assert_eq!(
+            grid_a.to_string(),
+            "....#...##\n\
+             ....#...#.\n\
+             ..###...#.\n\
+             ......o.#.\n\
+             ....oooo#.\n\
+             #########.\n"
+        );
+
+        assert_eq!(Day14::part_a(&segments).unwrap(), 24);
+        assert_eq!(Day14::part_b(&segments).unwrap(), 93);
+    }
+}
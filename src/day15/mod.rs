@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{
+    geom::Point2,
+    par,
+    parse::{self, ParseError, Parser},
+    Solution,
+};
+
+fn parse_point(p: &mut Parser) -> Result<Point2<i64>, ParseError> {
+    p.literal("x=")?;
+    let x = p.int()?;
+    p.literal(", y=")?;
+    let y = p.int()?;
+
+    Ok(Point2::new(x, y))
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    sensor: Point2<i64>,
+    beacon: Point2<i64>,
+}
+
+impl Sensor {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, |p| {
+            p.literal("Sensor at ")?;
+            let sensor = parse_point(p)?;
+            p.literal(": closest beacon is at ")?;
+            let beacon = parse_point(p)?;
+
+            Ok(Self { sensor, beacon })
+        })
+    }
+
+    pub fn radius(&self) -> i64 {
+        self.sensor.manhattan(&self.beacon)
+    }
+
+    fn at(&self, y: i64) -> Option<Segment> {
+        let dy = (self.sensor.y - y).abs();
+        let radius = self.radius() - dy;
+
+        if radius >= 0 {
+            Some(Segment {
+                a: self.sensor.x - radius,
+                b: self.sensor.x + radius,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Segment {
+    a: i64,
+    b: i64,
+}
+
+impl Segment {
+    pub fn len(&self) -> i64 {
+        self.b - self.a
+    }
+}
+
+#[derive(Debug)]
+struct SegmentSet {
+    segments: VecDeque<Segment>,
+}
+
+impl SegmentSet {
+    pub fn new() -> Self {
+        Self {
+            segments: VecDeque::new(),
+        }
+    }
+
+    pub fn add(&mut self, segment: &Segment) {
+        if let Some(last) = self.segments.back_mut() {
+            if segment.a > last.b + 1 {
+                self.segments.push_back(segment.clone());
+            } else {
+                last.b = last.b.max(segment.b);
+            }
+        } else {
+            self.segments.push_back(segment.clone())
+        }
+    }
+
+    pub fn len(&self) -> i64 {
+        self.segments.iter().map(|s| s.len()).sum()
+    }
+
+    pub fn hole(&self) -> Option<i64> {
+        if self.segments.len() == 2 {
+            Some(self.segments[0].b + 1)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Field {
+    sensors: Vec<Sensor>,
+    /// Row checked in part A.
+    row: i64,
+    /// Part B searches `0..=limit` in both directions.
+    limit: i64,
+}
+
+impl Field {
+    pub fn new(sensors: Vec<Sensor>) -> Self {
+        Self {
+            sensors,
+            row: TARGET_A,
+            limit: TARGET_B,
+        }
+    }
+
+    pub fn with_targets(mut self, row: i64, limit: i64) -> Self {
+        self.row = row;
+        self.limit = limit;
+        self
+    }
+
+    fn at(&self, target: i64) -> SegmentSet {
+        let mut segments: Vec<_> = self.sensors.iter().filter_map(|s| s.at(target)).collect();
+        segments.sort_by_key(|s| s.a);
+
+        let mut set = SegmentSet::new();
+
+        for s in &segments {
+            set.add(s)
+        }
+
+        set
+    }
+
+    pub fn hole_at(&self, target: i64) -> Option<(i64, i64)> {
+        let set = self.at(target);
+        set.hole().map(|x| (x, target))
+    }
+}
+
+const TARGET_A: i64 = 2000000;
+const TARGET_B: i64 = 4000000;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Field;
+    type A = i64;
+    type B = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let sensors = parse::lines(input, Sensor::parse)?;
+        Ok(Field::new(sensors))
+    }
+
+    fn part_a(field: &Self::Input) -> Result<i64> {
+        Ok(field.at(field.row).len())
+    }
+
+    fn part_b(field: &Self::Input) -> Result<i64> {
+        let (x, y) = par::find_map_first(0..=field.limit, |y| field.hole_at(y))
+            .ok_or_else(|| anyhow!("Task B: hole not found"))?;
+
+        Ok(x * 4000000 + y)
+    }
+
+    /// `row` of part A and `limit` of part B.
+    fn configure(field: &mut Self::Input, name: &str, value: &str) -> Result<()> {
+        let value = value
+            .parse()
+            .map_err(|_| anyhow!("Invalid {}: {}", name, value))?;
+
+        match name {
+            "row" => field.row = value,
+            "limit" => field.limit = value,
+            _ => bail!("Day 15 has no parameter {}", name),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_targets() {
+        let input = include_str!("../../examples/day15.txt");
+        let field = Day15::parse(input).unwrap().with_targets(10, 20);
+
+        assert_eq!(Day15::part_a(&field).unwrap(), 26);
+        assert_eq!(Day15::part_b(&field).unwrap(), 56000011);
+
+        let mut field = field;
+        assert!(Day15::configure(&mut field, "row", "ten").is_err());
+        assert!(Day15::configure(&mut field, "depth", "1").is_err());
+        Day15::configure(&mut field, "row", "11").unwrap();
+        assert_eq!(field.row, 11);
+    }
+}
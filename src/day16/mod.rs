@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{
+    parse::{self, ParseError, Parser},
+    verbose, NotImplemented, Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Room {
+    name: String,
+    rate: i32,
+    dst: Vec<String>,
+}
+
+impl Room {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, |p| {
+            p.literal("Valve ")?;
+            let name = p.word("valve name")?.to_owned();
+            p.literal(" has flow rate=")?;
+            let rate = p.int()?;
+
+            if !p.try_literal("; tunnels lead to valves ") {
+                p.literal("; tunnel leads to valve ")?;
+            }
+
+            let dst = p.separated(", ", |p| Ok(p.word("valve name")?.to_owned()))?;
+
+            Ok(Self { name, rate, dst })
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    rooms: Vec<Room>,
+    valves: usize,
+    total: i32,
+}
+
+impl Map {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let rooms = parse::lines(input, Room::parse)?;
+
+        for (idx, (line, room)) in input.lines().zip(&rooms).enumerate() {
+            let mut offset = line.len() - room.dst.join(", ").len();
+            for dst in &room.dst {
+                if !rooms.iter().any(|r| r.name == *dst) {
+                    return Err(ParseError::new("known valve", line, offset).shift_lines(idx));
+                }
+                offset += dst.len() + ", ".len();
+            }
+        }
+
+        if !rooms.iter().any(|r| r.name == START) {
+            return Err(ParseError::new(
+                format!("valve {}", START),
+                input,
+                input.len(),
+            ));
+        }
+
+        let valves = rooms.iter().filter(|r| r.rate > 0).count();
+        let total = rooms.iter().map(|r| r.rate).sum();
+
+        Ok(Self {
+            rooms,
+            valves,
+            total,
+        })
+    }
+
+    pub fn find(&self, name: &str) -> Result<&Room> {
+        self.rooms
+            .iter()
+            .find(|el| el.name == name)
+            .ok_or_else(|| anyhow!("There is no valve {}", name))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct State {
+    current: String,
+    open: Vec<String>,
+    per_min: i32,
+    total: i32,
+}
+
+impl State {
+    fn new(current: &str) -> Self {
+        let current = current.to_owned();
+        let open = vec![];
+
+        Self {
+            current,
+            open,
+            per_min: 0,
+            total: 0,
+        }
+    }
+
+    fn process(&self, action: &Action, map: &Map) -> Result<Self> {
+        let room = map.find(&self.current)?;
+        let mut state = self.clone();
+        state.total += state.per_min;
+
+        match action {
+            Action::Open => {
+                state.per_min += room.rate;
+                state.open.push(self.current.clone());
+                state.open.sort();
+            }
+            Action::MoveTo(to) => state.current = to.clone(),
+            Action::Sleep => {}
+        };
+
+        Ok(state)
+    }
+}
+
+pub struct Game {
+    map: Map,
+    states: Vec<State>,
+    remnant: usize,
+}
+
+impl Game {
+    pub fn new(map: Map, steps: usize) -> Self {
+        let states = vec![State::new(START)];
+        Self {
+            map,
+            states,
+            remnant: steps,
+        }
+    }
+
+    pub fn tick(&mut self) -> Result<()> {
+        self.remnant -= 1;
+
+        type Key = (String, Vec<String>);
+        let mut states: HashMap<Key, State> = HashMap::new();
+
+        for s in &self.states {
+            for action in self.actions(s, &self.map)? {
+                let next = s.process(&action, &self.map)?;
+                let total = next.total;
+                let key: Key = (next.current.clone(), next.open.clone());
+                let entry = states.entry(key).or_insert(next);
+                if total > entry.total {
+                    entry.total = total
+                }
+            }
+        }
+
+        let states: Vec<_> = states.drain().map(|(_, v)| v).collect();
+
+        let actual_max = self
+            .states
+            .iter()
+            .map(|s| s.total + s.per_min * self.remnant as i32)
+            .max()
+            .ok_or_else(|| anyhow!("No states left"))?;
+
+        let states = states
+            .into_iter()
+            .filter(|s| {
+                let poss = s.total + self.map.total * self.remnant as i32;
+                poss >= actual_max
+            })
+            .collect();
+
+        self.states = states;
+        Ok(())
+    }
+
+    fn actions(&self, state: &State, map: &Map) -> Result<Vec<Action>> {
+        let room = self.map.find(&state.current)?;
+        let mut actions = vec![];
+
+        if state.open.len() == map.valves {
+            actions.push(Action::Sleep);
+            return Ok(actions);
+        }
+
+        for dst in &room.dst {
+            actions.push(Action::MoveTo(dst.clone()))
+        }
+
+        if room.rate > 0 && !state.open.contains(&state.current) {
+            actions.push(Action::Open);
+        }
+
+        Ok(actions)
+    }
+}
+
+pub enum Action {
+    Open,
+    MoveTo(String),
+    Sleep,
+}
+
+const START: &str = "AA";
+const STEPS_A: usize = 30;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Map;
+    type A = i32;
+    type B = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Map::parse(input)?)
+    }
+
+    fn part_a(map: &Self::Input) -> Result<i32> {
+        let mut game = Game::new(map.clone(), STEPS_A);
+        for n in 1..=STEPS_A {
+            game.tick()?;
+            verbose!("Tick {} - {} - {}", n, game.states.len(), game.remnant);
+        }
+
+        game.states
+            .iter()
+            .map(|s| s.total)
+            .max()
+            .ok_or_else(|| anyhow!("No states left"))
+    }
+
+    fn part_b(_map: &Self::Input) -> Result<i32> {
+        bail!(NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_valves() {
+        let example = include_str!("../../examples/day16.txt");
+
+        let err =
+            Map::parse(&example.replace("valves DD, II, BB", "valves DD, XX, BB")).unwrap_err();
+        assert_eq!(
+            (err.line(), err.column(), err.expected()),
+            (1, 54, "known valve")
+        );
+
+        let err = Map::parse(&example.replace("AA", "ZZ")).unwrap_err();
+        assert_eq!((err.expected(), err.found()), ("valve AA", "end of input"));
+    }
+}
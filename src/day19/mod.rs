@@ -0,0 +1,264 @@
+use anyhow::Result;
+
+use crate::{
+    par,
+    parse::{self, ParseError, Parser},
+    Solution,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Robot {
+    /// Most useful first, which finds good results early and prunes more.
+    const ALL: [Robot; 4] = [Robot::Geode, Robot::Obsidian, Robot::Clay, Robot::Ore];
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Blueprint {
+    id: u32,
+    ore_robot_ore: u32,
+    clay_robot_ore: u32,
+    obsidian_robor_ore: u32,
+    obsidian_robot_clay: u32,
+    geode_robot_ore: u32,
+    geode_robot_obsidian: u32,
+}
+
+impl Blueprint {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input, |p| {
+            let mut number = |before: &str, after: &str| -> Result<u32, ParseError> {
+                p.whitespace();
+                p.literal(before)?;
+                let value = p.int()?;
+                p.literal(after)?;
+                Ok(value)
+            };
+
+            Ok(Self {
+                id: number("Blueprint ", ":")?,
+                ore_robot_ore: number("Each ore robot costs ", " ore.")?,
+                clay_robot_ore: number("Each clay robot costs ", " ore.")?,
+                obsidian_robor_ore: number("Each obsidian robot costs ", " ore")?,
+                obsidian_robot_clay: number("and ", " clay.")?,
+                geode_robot_ore: number("Each geode robot costs ", " ore")?,
+                geode_robot_obsidian: number("and ", " obsidian.")?,
+            })
+        })
+    }
+
+    /// Ore, clay and obsidian a robot costs.
+    fn cost(&self, robot: Robot) -> [u32; 3] {
+        match robot {
+            Robot::Ore => [self.ore_robot_ore, 0, 0],
+            Robot::Clay => [self.clay_robot_ore, 0, 0],
+            Robot::Obsidian => [self.obsidian_robor_ore, self.obsidian_robot_clay, 0],
+            Robot::Geode => [self.geode_robot_ore, 0, self.geode_robot_obsidian],
+        }
+    }
+
+    /// Robots of a kind worth having, a robot can only be built once a minute
+    /// so more than the highest cost of its mineral is never spent.
+    fn limit(&self, robot: Robot) -> u32 {
+        match robot {
+            Robot::Ore => [Robot::Clay, Robot::Obsidian, Robot::Geode]
+                .into_iter()
+                .map(|robot| self.cost(robot)[0])
+                .max()
+                .unwrap_or(0),
+            Robot::Clay => self.obsidian_robot_clay,
+            Robot::Obsidian => self.geode_robot_obsidian,
+            Robot::Geode => u32::MAX,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct State {
+    /// Ore, clay and obsidian collected.
+    minerals: [u32; 3],
+    /// Ore, clay and obsidian robots.
+    robots: [u32; 3],
+    /// Geodes the robots built so far crack until the end.
+    geodes: u32,
+    minutes: u32,
+}
+
+impl State {
+    pub fn new(minutes: u32) -> Self {
+        Self {
+            minerals: [0; 3],
+            robots: [1, 0, 0],
+            geodes: 0,
+            minutes,
+        }
+    }
+
+    /// Waits until `robot` is affordable and builds it, `None` if it would
+    /// not be ready before the end.
+    pub fn build(&self, robot: Robot, blueprint: &Blueprint) -> Option<Self> {
+        let cost = blueprint.cost(robot);
+
+        let mut wait = 0;
+        for ((cost, have), robots) in cost.iter().zip(self.minerals).zip(self.robots) {
+            if *cost > have {
+                if robots == 0 {
+                    return None;
+                }
+                wait = wait.max((cost - have).div_ceil(robots));
+            }
+        }
+
+        let minutes = self
+            .minutes
+            .checked_sub(wait + 1)
+            .filter(|left| *left > 0)?;
+        let mut state = self.clone();
+        state.minutes = minutes;
+        for ((mineral, robots), cost) in state.minerals.iter_mut().zip(self.robots).zip(cost) {
+            *mineral = *mineral + robots * (wait + 1) - cost;
+        }
+
+        match robot {
+            Robot::Ore => state.robots[0] += 1,
+            Robot::Clay => state.robots[1] += 1,
+            Robot::Obsidian => state.robots[2] += 1,
+            Robot::Geode => state.geodes += minutes,
+        }
+
+        Some(state)
+    }
+
+    /// Geodes if a geode robot could be built every minute left.
+    fn bound(&self) -> u32 {
+        let left = self.minutes.saturating_sub(1);
+        self.geodes + left * (left + 1) / 2
+    }
+
+    fn robots(&self, robot: Robot) -> u32 {
+        match robot {
+            Robot::Ore => self.robots[0],
+            Robot::Clay => self.robots[1],
+            Robot::Obsidian => self.robots[2],
+            Robot::Geode => 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    blueprint: Blueprint,
+    minutes: u32,
+}
+
+impl Game {
+    pub fn new(blueprint: Blueprint, minutes: u32) -> Self {
+        Self { blueprint, minutes }
+    }
+
+    pub fn score_a(&self) -> u32 {
+        self.max_geode() * self.blueprint.id
+    }
+
+    pub fn score_b(&self) -> u32 {
+        self.max_geode()
+    }
+
+    /// Depth first over the next robot to build, branches which can't beat
+    /// the best result so far are skipped.
+    fn max_geode(&self) -> u32 {
+        let mut best = 0;
+        self.search(&State::new(self.minutes), &mut best);
+        best
+    }
+
+    fn search(&self, state: &State, best: &mut u32) {
+        *best = (*best).max(state.geodes);
+        if state.bound() <= *best {
+            return;
+        }
+
+        for robot in Robot::ALL {
+            if state.robots(robot) >= self.blueprint.limit(robot) {
+                continue;
+            }
+
+            if let Some(next) = state.build(robot, &self.blueprint) {
+                self.search(&next, best);
+            }
+        }
+    }
+}
+
+const GAME_A_COUNT: u32 = 24;
+const GAME_B_COUNT: u32 = 32;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Vec<Blueprint>;
+    type A = u32;
+    type B = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input, Blueprint::parse)?)
+    }
+
+    fn part_a(blueprints: &Self::Input) -> Result<u32> {
+        let scores = par::map(blueprints, |blueprint| {
+            Game::new(blueprint.clone(), GAME_A_COUNT).score_a()
+        });
+
+        Ok(scores.iter().sum())
+    }
+
+    fn part_b(blueprints: &Self::Input) -> Result<u32> {
+        let blueprints = &blueprints[..blueprints.len().min(3)];
+        let scores = par::map(blueprints, |blueprint| {
+            Game::new(blueprint.clone(), GAME_B_COUNT).score_b()
+        });
+
+        Ok(scores.iter().product())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_blueprint_parse() {
+        let input = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.";
+        let expected = Blueprint {
+            id: 1,
+            ore_robot_ore: 4,
+            clay_robot_ore: 2,
+            obsidian_robor_ore: 3,
+            obsidian_robot_clay: 14,
+            geode_robot_ore: 2,
+            geode_robot_obsidian: 7,
+        };
+
+        assert_eq!(Blueprint::parse(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_max_geode() {
+        let input = include_str!("../../examples/day19.txt");
+        let blueprints = Day19::parse(input).unwrap();
+        let game = |idx: usize, minutes| Game::new(blueprints[idx].clone(), minutes);
+
+        assert_eq!(game(0, 24).max_geode(), 9);
+        assert_eq!(game(1, 24).max_geode(), 12);
+        assert_eq!(game(0, 32).max_geode(), 56);
+        assert_eq!(game(1, 32).max_geode(), 62);
+    }
+}
@@ -0,0 +1,393 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Signed number usable as a point coordinate.
+pub trait Scalar:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn signum(self) -> Self {
+        match self.cmp(&Self::ZERO) {
+            std::cmp::Ordering::Less => -Self::ONE,
+            std::cmp::Ordering::Equal => Self::ZERO,
+            std::cmp::Ordering::Greater => Self::ONE,
+        }
+    }
+}
+
+macro_rules! scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+scalar!(i8, i16, i32, i64, i128, isize);
+
+/// Point on a plane in screen coordinates, `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Scalar> Point2<T> {
+    pub fn zero() -> Self {
+        Self::new(T::ZERO, T::ZERO)
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Componentwise signum, a unit step towards the point.
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Quarter turn clockwise around the origin, as seen on screen.
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Quarter turn counter-clockwise around the origin, as seen on screen.
+    pub fn rotate_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn step(&self, dir: Dir4) -> Self {
+        *self + dir.offset()
+    }
+
+    pub fn neighbors4(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        Dir4::ALL.iter().map(move |dir| point.step(*dir))
+    }
+
+    pub fn neighbors8(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        Dir8::ALL.iter().map(move |dir| point + dir.offset())
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// Point in space, rotations follow the right-hand rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Scalar> Point3<T> {
+    pub fn zero() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// Quarter turn around the x axis.
+    pub fn rotate_x(&self) -> Self {
+        Self::new(self.x, -self.z, self.y)
+    }
+
+    /// Quarter turn around the y axis.
+    pub fn rotate_y(&self) -> Self {
+        Self::new(self.z, self.y, -self.x)
+    }
+
+    /// Quarter turn around the z axis.
+    pub fn rotate_z(&self) -> Self {
+        Self::new(-self.y, self.x, self.z)
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        let point = *self;
+
+        [
+            Self::new(-one, zero, zero),
+            Self::new(one, zero, zero),
+            Self::new(zero, -one, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, zero, -one),
+            Self::new(zero, zero, one),
+        ]
+        .into_iter()
+        .map(move |offset| point + offset)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+macro_rules! ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, k: T) -> Self {
+                Self { $($field: self.$field * k),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)*
+            }
+        }
+    };
+}
+
+ops!(Point2 { x, y });
+ops!(Point3 { x, y, z });
+
+/// Orthogonal direction on screen, `Up` decreases `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn offset<T: Scalar>(&self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+
+        match self {
+            Dir4::Up => Point2::new(zero, -one),
+            Dir4::Right => Point2::new(one, zero),
+            Dir4::Down => Point2::new(zero, one),
+            Dir4::Left => Point2::new(-one, zero),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+/// Direction on screen including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn offset<T: Scalar>(&self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+
+        match self {
+            Dir8::Up => Point2::new(zero, -one),
+            Dir8::UpRight => Point2::new(one, -one),
+            Dir8::Right => Point2::new(one, zero),
+            Dir8::DownRight => Point2::new(one, one),
+            Dir8::Down => Point2::new(zero, one),
+            Dir8::DownLeft => Point2::new(-one, one),
+            Dir8::Left => Point2::new(-one, zero),
+            Dir8::UpLeft => Point2::new(-one, -one),
+        }
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn counter-clockwise.
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 7) % 8]
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self::ALL[(*self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::Up,
+            Dir4::Right => Dir8::Right,
+            Dir4::Down => Dir8::Down,
+            Dir4::Left => Dir8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(a.chebyshev(&b), 7);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+
+        let c = Point3::new(1i64, 2, 3);
+        assert_eq!(c.manhattan(&Point3::zero()), 6);
+        assert_eq!(c.chebyshev(&Point3::zero()), 3);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let right = Dir4::Right.offset::<i32>();
+        assert_eq!(right.rotate_right(), Dir4::Down.offset());
+        assert_eq!(right.rotate_left(), Dir4::Up.offset());
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+
+        let x = Point3::new(1, 0, 0);
+        assert_eq!(x.rotate_z(), Point3::new(0, 1, 0));
+        assert_eq!(x.rotate_z().rotate_x(), Point3::new(0, 0, 1));
+        assert_eq!(x.rotate_y(), Point3::new(0, 0, -1));
+        assert_eq!(x.cross(&x.rotate_z()), Point3::new(0, 0, 1));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let p = Point2::new(0, 0);
+        assert_eq!(p.neighbors4().map(|n| n.manhattan(&p)).sum::<i32>(), 4);
+        assert_eq!(p.neighbors8().filter(|n| n.chebyshev(&p) == 1).count(), 8);
+        assert_eq!(Point3::new(0, 0, 0).neighbors6().count(), 6);
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{geom::Point2, parse::ParseError};
+
+/// `(x, y)` cell of a dense [`Grid`], `y` grows downwards.
+pub type Coord = (usize, usize);
+
+/// Cell of a [`SparseGrid`], may be negative.
+pub type Pos = Point2<i32>;
+
+/// Row-major grid with a fixed size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses a character map, one row per line. Every character is mapped
+    /// with `f`, `None` is reported as `expected`.
+    pub fn parse<F>(input: &str, expected: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+
+            for (offset, c) in line.char_indices() {
+                let cell =
+                    f(c).ok_or_else(|| ParseError::new(expected, line, offset).shift_lines(y))?;
+                cells.push(cell);
+            }
+
+            let row = cells.len() - row_start;
+            match width {
+                None => width = Some(row),
+                Some(width) if width != row => {
+                    let offset = line
+                        .char_indices()
+                        .nth(width)
+                        .map(|(offset, _)| offset)
+                        .unwrap_or(line.len());
+                    let expected = format!("row of {} cells", width);
+                    return Err(ParseError::new(expected, line, offset).shift_lines(y));
+                }
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// All coordinates, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// Orthogonal neighbors of `coord` which lie inside the grid.
+    pub fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Self::pos(coord)
+            .neighbors4()
+            .filter_map(|pos| self.coord(pos))
+    }
+
+    /// Neighbors of `coord` including diagonals which lie inside the grid.
+    pub fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Self::pos(coord)
+            .neighbors8()
+            .filter_map(|pos| self.coord(pos))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index_of(&self, (x, y): Coord) -> Option<usize> {
+        if self.contains((x, y)) {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    fn pos((x, y): Coord) -> Pos {
+        Pos::new(x as i32, y as i32)
+    }
+
+    fn coord(&self, pos: Pos) -> Option<Coord> {
+        let coord = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        self.contains(coord).then_some(coord)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        let idx = self.index_of(coord).expect("Coord outside of grid");
+        &self.cells[idx]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let idx = self.index_of(coord).expect("Coord outside of grid");
+        &mut self.cells[idx]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Smallest rectangle holding a set of positions, both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    pub fn new(pos: Pos) -> Self {
+        Self { min: pos, max: pos }
+    }
+
+    pub fn add(&mut self, pos: Pos) {
+        self.min = Pos::new(self.min.x.min(pos.x), self.min.y.min(pos.y));
+        self.max = Pos::new(self.max.x.max(pos.x), self.max.y.max(pos.y));
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x) && (self.min.y..=self.max.y).contains(&pos.y)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y) as usize + 1
+    }
+}
+
+/// Unbounded grid storing only the occupied cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a character map, `empty` characters are left out. Every other
+    /// character is mapped with `f`, `None` is reported as `expected`.
+    pub fn parse<F>(input: &str, empty: char, expected: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut grid = Self::new();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, (offset, c)) in line.char_indices().enumerate() {
+                if c == empty {
+                    continue;
+                }
+
+                let cell =
+                    f(c).ok_or_else(|| ParseError::new(expected, line, offset).shift_lines(y))?;
+                grid.insert(Pos::new(x as i32, y as i32), cell);
+            }
+        }
+
+        Ok(grid)
+    }
+
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.add(pos),
+            None => self.bounds = Some(Bounds::new(pos)),
+        }
+
+        self.cells.insert(pos, value)
+    }
+
+    /// Removes the cell, the bounds are kept as they were.
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Bounds of every position inserted so far, `None` for a new grid.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    /// Occupied orthogonal neighbors of `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbors4()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Occupied neighbors of `pos` including diagonals.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbors8()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+}
+
+/// Renders the bounding box, missing cells are drawn as `.`.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                match self.get(Pos::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("123\n456", "digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let err = Grid::parse("123\n45\n", "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert_eq!(err.expected(), "row of 3 cells");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 2)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut grid = SparseGrid::parse(".#.\n..#\n###", '.', "'#'", Some).unwrap();
+        grid.insert(Pos::new(-1, 0), 'o');
+
+        assert_eq!(grid.len(), 6);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Pos::new(-1, 0),
+                max: Pos::new(2, 2)
+            })
+        );
+        assert_eq!(grid.neighbors8(Pos::new(1, 1)).count(), 5);
+        assert_eq!(grid.to_string(), "o.#.\n...#\n.###\n");
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "input";
+
+/// Reads the puzzle input for the running binary: the file named in the first
+/// argument, stdin for `-`, or `dayNN.txt` in the input directory when the
+/// binary is called `dayNN` and no argument is given.
+pub fn read_input() -> Result<String> {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_default();
+    let source = args.next();
+
+    match (day_from_program(&program), source) {
+        (Some(day), source) => read_day(day, source.as_deref()),
+        (None, Some(source)) => read_source(&source),
+        (None, None) => bail!("missing input filename"),
+    }
+}
+
+/// Reads the input of `day` from `source`, or `dayNN.txt` in the input
+/// directory without one.
+pub fn read_day(day: u8, source: Option<&str>) -> Result<String> {
+    match source {
+        Some(source) => read_source(source),
+        None => read_day_input(day),
+    }
+}
+
+/// Reads `-` from stdin and anything else as a file path.
+pub fn read_source(source: &str) -> Result<String> {
+    if source == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        read_file(Path::new(source))
+    }
+}
+
+pub fn read_day_input(day: u8) -> Result<String> {
+    read_file(&input_path(day))
+}
+
+/// Directory with `dayNN.txt` files, `AOC_INPUT_DIR` or `input` by default.
+pub fn input_dir() -> PathBuf {
+    std::env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+pub fn input_path(day: u8) -> PathBuf {
+    day_path(&input_dir(), day)
+}
+
+fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}.txt", day))
+}
+
+pub(crate) fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|err| anyhow!("can't read {}: {}", path.display(), err))
+}
+
+fn day_from_program(program: &str) -> Option<u8> {
+    let name = Path::new(program).file_stem()?.to_str()?;
+    name.strip_prefix("day")?.parse::<u8>().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day_from_program() {
+        assert_eq!(day_from_program("target/release/day07"), Some(7));
+        assert_eq!(day_from_program("day19.exe"), Some(19));
+        assert_eq!(day_from_program("target/debug/aoc"), None);
+        assert_eq!(day_from_program(""), None);
+    }
+
+    #[test]
+    fn test_input_dir_var() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day05.txt"), "stacks\n").unwrap();
+
+        std::env::set_var(INPUT_DIR_VAR, &dir);
+        let found = (input_dir(), read_day(5, None).ok());
+        std::env::remove_var(INPUT_DIR_VAR);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found, (dir, Some("stacks\n".into())));
+        assert_eq!(input_dir(), PathBuf::from(DEFAULT_INPUT_DIR));
+    }
+
+    #[test]
+    fn test_day_path() {
+        let path = day_path(Path::new("/tmp/inputs"), 5);
+        assert_eq!(path, PathBuf::from("/tmp/inputs/day05.txt"));
+    }
+}
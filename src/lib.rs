@@ -1,9 +1,31 @@
-use anyhow::{anyhow, Result};
+pub mod answers;
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day19;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod par;
+pub mod parse;
+pub mod registry;
+pub mod search;
+mod solution;
 
-pub fn read_input() -> Result<String> {
-    let filename = std::env::args()
-        .nth(1)
-        .ok_or_else(|| anyhow!("missing input filename"))?;
-
-    Ok(std::fs::read_to_string(&filename)?)
-}
+pub use input::read_input;
+pub use solution::{NotImplemented, Solution};
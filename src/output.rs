@@ -0,0 +1,389 @@
+use std::{
+    fmt::Write as _,
+    io::Write,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{
+    input,
+    registry::{Answer, Day, Part},
+    Solution,
+};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Debug output of the solvers, printed to stderr with `--verbose` only.
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::output::is_verbose() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+impl Format {
+    pub fn parse(input: &str) -> Result<Self> {
+        match input {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "tsv" => Ok(Self::Tsv),
+            _ => bail!("Invalid format: {}", input),
+        }
+    }
+}
+
+/// Prints answers of one or more days in a common format. Text and TSV are
+/// written as they come, JSON as a single array by [`Printer::finish`]. Text
+/// errors go to `err` so that `out` only holds answers.
+pub struct Printer<W: Write, E: Write = std::io::Stderr> {
+    format: Format,
+    out: W,
+    err: E,
+    records: Vec<String>,
+    header: bool,
+    failed: bool,
+}
+
+impl Printer<std::io::Stdout> {
+    pub fn stdout(format: Format) -> Self {
+        Self::new(format, std::io::stdout(), std::io::stderr())
+    }
+}
+
+impl<W: Write, E: Write> Printer<W, E> {
+    pub fn new(format: Format, out: W, err: E) -> Self {
+        Self {
+            format,
+            out,
+            err,
+            records: vec![],
+            header: false,
+            failed: false,
+        }
+    }
+
+    /// Prints the answers of `day`, a part which is not implemented is
+    /// reported as missing and does not count as a failure.
+    pub fn answers(&mut self, day: u8, answers: &[Answer]) -> Result<()> {
+        for answer in answers {
+            let status = match &answer.value {
+                Ok(value) => Status::Answer(value),
+                Err(err) if answer.is_missing() => Status::Missing(err.to_string()),
+                Err(err) => Status::Error(err.to_string()),
+            };
+            self.record(day, Some(answer.part), status)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reports a day which could not be solved at all, e.g. on a parse error.
+    pub fn error(&mut self, day: u8, err: &anyhow::Error) -> Result<()> {
+        self.record(day, None, Status::Error(err.to_string()))
+    }
+
+    /// Prints what is left and tells if every answer was found.
+    pub fn finish(mut self) -> Result<bool> {
+        if self.format == Format::Json {
+            let records = self.records.join(",\n  ");
+            match records.is_empty() {
+                true => writeln!(self.out, "[]")?,
+                false => writeln!(self.out, "[\n  {}\n]", records)?,
+            }
+        }
+
+        self.out.flush()?;
+        self.err.flush()?;
+        Ok(!self.failed)
+    }
+
+    fn record(&mut self, day: u8, part: Option<Part>, status: Status) -> Result<()> {
+        self.failed |= matches!(status, Status::Error(_));
+
+        match self.format {
+            Format::Text => self.text(day, part, status),
+            Format::Json => {
+                self.records.push(json_record(day, part, status));
+                Ok(())
+            }
+            Format::Tsv => self.tsv(day, part, status),
+        }
+    }
+
+    fn text(&mut self, day: u8, part: Option<Part>, status: Status) -> Result<()> {
+        let label = match part {
+            Some(part) => format!("Day {:02} part {}", day, part),
+            None => format!("Day {:02}", day),
+        };
+
+        match status {
+            Status::Answer(answer) if answer.contains('\n') => {
+                writeln!(self.out, "{}:\n{}", label, answer.trim_end())?
+            }
+            Status::Answer(answer) => writeln!(self.out, "{}: {}", label, answer)?,
+            Status::Missing(reason) => writeln!(self.out, "{}: missing ({})", label, reason)?,
+            Status::Error(err) if part.is_some() => {
+                writeln!(self.err, "{}: error: {}", label, err)?
+            }
+            Status::Error(err) => writeln!(self.err, "{}: {}", label, err)?,
+        }
+
+        Ok(())
+    }
+
+    fn tsv(&mut self, day: u8, part: Option<Part>, status: Status) -> Result<()> {
+        if !self.header {
+            writeln!(self.out, "day\tpart\tstatus\tvalue")?;
+            self.header = true;
+        }
+
+        let part = part.map(|part| part.to_string()).unwrap_or_default();
+        let (status, value) = match status {
+            Status::Answer(answer) => ("ok", answer.to_owned()),
+            Status::Missing(reason) => ("missing", reason),
+            Status::Error(err) => ("error", err),
+        };
+
+        writeln!(
+            self.out,
+            "{:02}\t{}\t{}\t{}",
+            day,
+            part,
+            status,
+            tsv_field(&value)
+        )?;
+
+        Ok(())
+    }
+}
+
+/// What is printed for a part, or for a whole day without a part.
+enum Status<'a> {
+    Answer(&'a str),
+    Missing(String),
+    Error(String),
+}
+
+fn json_record(day: u8, part: Option<Part>, status: Status) -> String {
+    let part = match part {
+        Some(part) => json_string(&part.to_string()),
+        None => "null".into(),
+    };
+
+    let (key, value) = match status {
+        Status::Answer(answer) => ("answer", json_string(answer)),
+        Status::Missing(reason) => ("missing", json_string(&reason)),
+        Status::Error(err) => ("error", json_string(&err)),
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"{}\":{}}}",
+        day, part, key, value
+    )
+}
+
+fn tsv_field(value: &str) -> String {
+    value
+        .trim_end()
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+pub fn json_string(value: &str) -> String {
+    let mut out = String::from('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+/// `main` of the `dayNN` binaries: `dayNN [--format F] [--verbose] [FILE|-]`,
+/// reads `dayNN.txt` from the input directory without a file.
+pub fn run_day<S: Solution>() -> Result<()> {
+    let mut format = Format::Text;
+    let mut source = None;
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--format requires a value"))?;
+                format = Format::parse(&value)?;
+            }
+            "--verbose" => set_verbose(true),
+            _ => source = Some(arg),
+        }
+    }
+
+    let raw = input::read_day(S::DAY, source.as_deref())?;
+
+    let mut printer = Printer::stdout(format);
+    match Day::of::<S>().solve(&raw, &Part::all()) {
+        Ok(answers) => printer.answers(S::DAY, &answers)?,
+        Err(err) => printer.error(S::DAY, &anyhow!("can't parse input: {}", err))?,
+    }
+
+    if !printer.finish()? {
+        bail!("Day {:02} is not solved", S::DAY);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::NotImplemented;
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer {
+                part: Part::A,
+                value: Ok("CMZ".into()),
+            },
+            Answer {
+                part: Part::B,
+                value: Err(anyhow!("Part B is \"not\" implemented")),
+            },
+        ]
+    }
+
+    fn print(format: Format) -> (String, String, bool) {
+        let (mut out, mut err) = (vec![], vec![]);
+        let mut printer = Printer::new(format, &mut out, &mut err);
+        printer.answers(5, &answers()).unwrap();
+        printer.error(6, &anyhow!("can't parse input")).unwrap();
+        let ok = printer.finish().unwrap();
+
+        let text = |bytes| String::from_utf8(bytes).unwrap();
+        (text(out), text(err), ok)
+    }
+
+    /// Fails every write like a closed pipe.
+    struct Closed;
+
+    impl Write for Closed {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_text() {
+        let (out, err, ok) = print(Format::Text);
+
+        assert!(!ok);
+        assert_eq!(out, "Day 05 part A: CMZ\n");
+        assert_eq!(
+            err,
+            "Day 05 part B: error: Part B is \"not\" implemented\n\
+            Day 06: can't parse input\n"
+        );
+    }
+
+    #[test]
+    fn test_write_error() {
+        let mut printer = Printer::new(Format::Text, Closed, vec![]);
+        assert!(printer.answers(5, &answers()).is_err());
+        assert!(printer.error(6, &anyhow!("can't parse input")).is_ok());
+
+        let mut printer = Printer::new(Format::Text, vec![], Closed);
+        assert!(printer.error(6, &anyhow!("can't parse input")).is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let (out, err, ok) = print(Format::Json);
+
+        assert!(!ok);
+        assert_eq!(
+            out,
+            "[\n  \
+            {\"day\":5,\"part\":\"A\",\"answer\":\"CMZ\"},\n  \
+            {\"day\":5,\"part\":\"B\",\"error\":\"Part B is \\\"not\\\" implemented\"},\n  \
+            {\"day\":6,\"part\":null,\"error\":\"can't parse input\"}\n]\n"
+        );
+        assert_eq!(err, "");
+    }
+
+    #[test]
+    fn test_tsv() {
+        let (out, _, _) = print(Format::Tsv);
+
+        assert_eq!(
+            out,
+            "day\tpart\tstatus\tvalue\n\
+            05\tA\tok\tCMZ\n\
+            05\tB\terror\tPart B is \"not\" implemented\n\
+            06\t\terror\tcan't parse input\n"
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let mut out = vec![];
+        let mut printer = Printer::new(Format::Tsv, &mut out, vec![]);
+        let answer = Answer {
+            part: Part::B,
+            value: Err(NotImplemented.into()),
+        };
+        printer.answers(16, &[answer]).unwrap();
+        assert!(printer.finish().unwrap());
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day\tpart\tstatus\tvalue\n16\tB\tmissing\tnot implemented\n"
+        );
+    }
+
+    #[test]
+    fn test_text_multiline() {
+        let mut out = vec![];
+        let mut printer = Printer::new(Format::Text, &mut out, vec![]);
+        let answer = Answer {
+            part: Part::B,
+            value: Ok("#.\n.#\n".into()),
+        };
+        printer.answers(10, &[answer]).unwrap();
+        assert!(printer.finish().unwrap());
+
+        assert_eq!(String::from_utf8(out).unwrap(), "Day 10 part B:\n#.\n.#\n");
+    }
+}
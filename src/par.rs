@@ -0,0 +1,52 @@
+//! Helpers for independent work, run on a thread pool with the `parallel`
+//! feature and sequentially without it. Results keep the input order.
+
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items.par_iter().map(f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    return items.iter().map(f).collect();
+}
+
+/// First value in `range` order for which `f` returns `Some`, even when a
+/// later one is found sooner.
+pub fn find_map_first<R, F>(range: RangeInclusive<i64>, f: F) -> Option<R>
+where
+    R: Send,
+    F: Fn(i64) -> Option<R> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return range.into_par_iter().find_map_first(f);
+
+    #[cfg(not(feature = "parallel"))]
+    return range.into_iter().find_map(f);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let items: Vec<_> = (0..100).collect();
+        assert_eq!(
+            map(&items, |n| n * 2),
+            (0..200).step_by(2).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            find_map_first(0..=1000, |n| (n % 7 == 6).then_some(n)),
+            Some(6)
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::grid::Grid;
+
+/// Parse failure with a 1-based position in the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<u8>,
+    line: usize,
+    column: usize,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    /// Error at byte `offset` of `input`, `input` may span several lines.
+    pub fn new(expected: impl Into<String>, input: &str, offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|n| n + 1).unwrap_or(0);
+        let column = input[line_start..offset].chars().count() + 1;
+
+        Self {
+            day: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: token(&input[offset..]),
+        }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Moves the error down by `lines`, for input parsed as a part of a bigger
+    /// text.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error right by `columns`, for input parsed from the middle of
+    /// a line.
+    pub fn shift_columns(mut self, columns: usize) -> Self {
+        if self.line == 1 {
+            self.column += columns;
+        }
+
+        self
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn token(rest: &str) -> String {
+    let line = rest.lines().next().unwrap_or("");
+
+    if line.is_empty() {
+        return if rest.is_empty() {
+            "end of input".into()
+        } else {
+            "end of line".into()
+        };
+    }
+
+    let token = line.split_whitespace().next().unwrap_or(line);
+    format!("{:?}", token.chars().take(20).collect::<String>())
+}
+
+/// Parses every line of `input` with `f`, errors are reported at their line.
+pub fn lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| f(line).map_err(|err| err.shift_lines(idx)))
+        .collect()
+}
+
+/// Parses blocks of lines separated by a blank line.
+pub fn blocks<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    let mut line = 0;
+
+    input
+        .split("\n\n")
+        .map(|block| {
+            let result = f(block).map_err(|err| err.shift_lines(line));
+            line += block.matches('\n').count() + 2;
+            result
+        })
+        .collect()
+}
+
+/// Parses a rectangular grid of characters, `f` maps a character to a cell.
+pub fn grid<T, F>(input: &str, expected: &str, f: F) -> Result<Grid<T>, ParseError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    Grid::parse(input, expected, f)
+}
+
+/// Cursor over a piece of input, errors are positioned relative to the whole
+/// of it so a parser may span several lines.
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    /// Parses the whole of `input` with `f`.
+    pub fn parse<T, F>(input: &'a str, f: F) -> Result<T, ParseError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, ParseError>,
+    {
+        let mut parser = Self::new(input);
+        let value = f(&mut parser)?;
+        parser.end()?;
+        Ok(value)
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(expected, self.pos)
+    }
+
+    pub fn error_at(&self, expected: impl Into<String>, offset: usize) -> ParseError {
+        ParseError::new(expected, self.input, offset)
+    }
+
+    pub fn try_literal(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.pos += literal.len();
+        }
+
+        found
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.try_literal(literal) {
+            return Ok(());
+        }
+
+        let expected = match literal.chars().count() {
+            1 => format!("'{}'", literal),
+            _ => format!("{:?}", literal),
+        };
+
+        Err(self.error(expected))
+    }
+
+    /// Takes at least one character matching `pred`.
+    pub fn take_while<P>(&mut self, expected: &str, pred: P) -> Result<&'a str, ParseError>
+    where
+        P: Fn(char) -> bool,
+    {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+
+        if len == 0 {
+            return Err(self.error(expected));
+        }
+
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Alphanumeric name such as a valve or a directory.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.take_while(expected, |c| c.is_alphanumeric() || c == '_')
+    }
+
+    /// Decimal integer with an optional minus sign.
+    pub fn int<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.pos;
+        let sign = usize::from(self.rest().starts_with('-'));
+        let digits = self.rest()[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest().len() - sign);
+
+        if digits == 0 {
+            return Err(self.error("number"));
+        }
+
+        let number = &self.rest()[..sign + digits];
+        let value = number
+            .parse::<T>()
+            .map_err(|_| self.error_at("number", start))?;
+
+        self.pos += number.len();
+        Ok(value)
+    }
+
+    /// One or more items separated by `sep`.
+    pub fn separated<T, F>(&mut self, sep: &str, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ParseError>,
+    {
+        let mut items = vec![item(self)?];
+
+        while self.try_literal(sep) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Skips spaces and line breaks.
+    pub fn whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips spaces and tabs but not line breaks.
+    pub fn spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.error("end of line")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let err = ParseError::new("number", "12\n3x4\n", 4);
+        assert_eq!(err.line(), 2);
+        assert_eq!(err.column(), 2);
+        assert_eq!(err.found(), "\"x4\"");
+    }
+
+    #[test]
+    fn test_end_of_line() {
+        let err = ParseError::new("number", "move 1 from", 11);
+        assert_eq!(err.column(), 12);
+        assert_eq!(err.found(), "end of input");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1\n2\n\n3\nx\n\n5";
+        let result = blocks(input, |block| {
+            lines(block, |line| {
+                line.parse::<i32>()
+                    .map_err(|_| ParseError::new("number", line, 0))
+            })
+        });
+
+        let err = result.unwrap_err();
+        assert_eq!((err.line(), err.column()), (5, 1));
+        assert_eq!(
+            err.with_day(1).to_string(),
+            "day 01, line 5, column 1: expected number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_parser() {
+        let result = Parser::parse("x=-12, y=7", |p| {
+            p.literal("x=")?;
+            let x = p.int::<i32>()?;
+            p.literal(", y=")?;
+            Ok((x, p.int::<i32>()?))
+        });
+        assert_eq!(result, Ok((-12, 7)));
+
+        let list = Parser::parse("1, 2, 3", |p| p.separated(", ", |p| p.int::<u8>()));
+        assert_eq!(list, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_parser_errors() {
+        let err = Parser::parse("1, 2,", |p| p.separated(", ", |p| p.int::<u8>())).unwrap_err();
+        assert_eq!((err.column(), err.expected()), (5, "end of line"));
+
+        let err = Parser::parse("a\nb -", |p| {
+            p.word("name")?;
+            p.whitespace();
+            p.literal("b ")?;
+            p.int::<i32>()
+        })
+        .unwrap_err();
+        assert_eq!((err.line(), err.column(), err.expected()), (2, 3, "number"));
+
+        let err = Parser::parse("300", |p| p.int::<u8>()).unwrap_err();
+        assert_eq!((err.column(), err.found()), (1, "\"300\""));
+
+        let err = Parser::parse("move", |p| p.literal("[")).unwrap_err();
+        assert_eq!(err.expected(), "'['");
+    }
+}
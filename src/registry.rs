@@ -0,0 +1,176 @@
+use std::{fmt::Display, path::PathBuf};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{
+    bench::{Stats, Timing},
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day16::Day16,
+    day19::Day19,
+    input,
+    parse::ParseError,
+    NotImplemented, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub fn parse(input: &str) -> Result<Self> {
+        match input {
+            "a" | "A" => Ok(Self::A),
+            "b" | "B" => Ok(Self::B),
+            _ => bail!("Invalid part: {}", input),
+        }
+    }
+
+    pub fn all() -> [Self; 2] {
+        [Self::A, Self::B]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::A => write!(f, "A"),
+            Self::B => write!(f, "B"),
+        }
+    }
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub value: Result<String>,
+}
+
+impl Answer {
+    /// Tells if the part has no solution yet, see [`NotImplemented`].
+    pub fn is_missing(&self) -> bool {
+        matches!(&self.value, Err(err) if err.is::<NotImplemented>())
+    }
+}
+
+/// Puzzle parameters by name, see [`Solution::configure`].
+pub type Params = [(String, String)];
+
+type SolveFn = fn(&str, &[Part], &Params) -> Result<Vec<Answer>>;
+type BenchFn = fn(&str, usize) -> Result<Timing>;
+
+pub struct Day {
+    pub day: u8,
+    solve: SolveFn,
+    bench: BenchFn,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve::<S>,
+            bench: bench::<S>,
+        }
+    }
+
+    /// Parses the input once and solves the requested parts, a failing part
+    /// does not prevent the other one from being solved.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>> {
+        self.solve_with(input, parts, &[])
+    }
+
+    /// [`Day::solve`] with some puzzle parameters overridden.
+    pub fn solve_with(&self, input: &str, parts: &[Part], params: &Params) -> Result<Vec<Answer>> {
+        (self.solve)(input, parts, params)
+    }
+
+    /// Times parsing and both parts separately over `runs` runs each.
+    pub fn bench(&self, input: &str, runs: usize) -> Result<Timing> {
+        (self.bench)(input, runs)
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        input::input_path(self.day)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<S::Input> {
+    S::parse(input).map_err(|err| match err.downcast::<ParseError>() {
+        Ok(err) => err.with_day(S::DAY).into(),
+        Err(err) => err,
+    })
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part], params: &Params) -> Result<Vec<Answer>> {
+    let mut parsed = parse::<S>(input)?;
+    for (name, value) in params {
+        S::configure(&mut parsed, name, value)?;
+    }
+
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let value = match part {
+                Part::A => S::part_a(&parsed).map(|answer| answer.to_string()),
+                Part::B => S::part_b(&parsed).map(|answer| answer.to_string()),
+            };
+
+            Answer { part: *part, value }
+        })
+        .collect();
+
+    Ok(answers)
+}
+
+fn bench<S: Solution>(input: &str, runs: usize) -> Result<Timing> {
+    let parse_stats = Stats::measure(runs, || parse::<S>(input))?;
+    let parsed = parse::<S>(input)?;
+
+    Ok(Timing {
+        day: S::DAY,
+        parse: parse_stats,
+        part_a: Stats::measure(runs, || S::part_a(&parsed)),
+        part_b: Stats::measure(runs, || S::part_b(&parsed)),
+    })
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<Day01>(),
+    Day::of::<Day02>(),
+    Day::of::<Day03>(),
+    Day::of::<Day04>(),
+    Day::of::<Day05>(),
+    Day::of::<Day06>(),
+    Day::of::<Day07>(),
+    Day::of::<Day08>(),
+    Day::of::<Day09>(),
+    Day::of::<Day10>(),
+    Day::of::<Day11>(),
+    Day::of::<Day12>(),
+    Day::of::<Day13>(),
+    Day::of::<Day14>(),
+    Day::of::<Day15>(),
+    Day::of::<Day16>(),
+    Day::of::<Day19>(),
+];
+
+pub fn find(day: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| anyhow!("Day {} is not solved", day))
+}
@@ -0,0 +1,246 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Nodes reached by a search with their cost and the node each one was
+/// reached from.
+#[derive(Debug, Clone)]
+pub struct Reached<N> {
+    costs: HashMap<N, usize>,
+    parents: HashMap<N, N>,
+    found: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Reached<N> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            found: None,
+        }
+    }
+
+    /// Lowers the cost of `node`, tells if it was lower.
+    fn relax(&mut self, node: &N, cost: usize, parent: Option<&N>) -> bool {
+        if self.costs.get(node).is_some_and(|known| *known <= cost) {
+            return false;
+        }
+
+        self.costs.insert(node.clone(), cost);
+        if let Some(parent) = parent {
+            self.parents.insert(node.clone(), parent.clone());
+        }
+
+        true
+    }
+
+    /// First goal node the search stopped at.
+    pub fn found(&self) -> Option<&N> {
+        self.found.as_ref()
+    }
+
+    /// Cost of the goal node the search stopped at.
+    pub fn found_cost(&self) -> Option<usize> {
+        self.cost(self.found.as_ref()?)
+    }
+
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, usize> {
+        &self.costs
+    }
+
+    /// Cheapest path from one of the start nodes to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth first search from `start` until a node matching `goal` is found,
+/// every edge costs 1.
+pub fn bfs<N, I, F, G>(start: N, neighbors: F, goal: G) -> Reached<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    bfs_multi([start], neighbors, goal)
+}
+
+/// Breadth first search from all `starts` at once, the cost of a node is
+/// the distance to the closest of them.
+pub fn bfs_multi<N, S, I, F, G>(starts: S, mut neighbors: F, mut goal: G) -> Reached<N>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let mut reached = Reached::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if reached.relax(&start, 0, None) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            reached.found = Some(node);
+            break;
+        }
+
+        let cost = reached.costs[&node] + 1;
+        for next in neighbors(&node) {
+            if reached.relax(&next, cost, Some(&node)) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    reached
+}
+
+/// Cheapest paths with edge costs given by `neighbors`.
+pub fn dijkstra<N, I, F, G>(start: N, neighbors: F, goal: G) -> Reached<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbors, |_| 0, goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must never overestimate the
+/// cost left to a goal.
+pub fn astar<N, I, F, H, G>(start: N, mut neighbors: F, mut heuristic: H, mut goal: G) -> Reached<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> usize,
+    G: FnMut(&N) -> bool,
+{
+    let mut reached = Reached::new();
+    reached.relax(&start, 0, None);
+
+    // the heap holds indices into `nodes`, so nodes don't have to be `Ord`
+    let mut heap = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(0), 0)]);
+    let mut nodes = vec![start];
+
+    while let Some((_, Reverse(cost), idx)) = heap.pop() {
+        let node = nodes[idx].clone();
+        if reached.costs[&node] < cost {
+            continue;
+        }
+
+        if goal(&node) {
+            reached.found = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if reached.relax(&next, next_cost, Some(&node)) {
+                let estimate = next_cost + heuristic(&next);
+                heap.push((Reverse(estimate), Reverse(next_cost), nodes.len()));
+                nodes.push(next);
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::{Coord, Grid};
+
+    fn maze() -> Grid<bool> {
+        let input = "\
+            ..#....\n\
+            .##.##.\n\
+            ....#..\n\
+            .##.#.#\n\
+            ......."
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Grid::parse(&input, "'.' or '#'", |c| Some(c == '.')).unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let open = |coord: &Coord| -> Vec<Coord> {
+            maze.neighbors4(*coord).filter(|next| maze[*next]).collect()
+        };
+
+        let reached = bfs((0, 0), open, |coord| *coord == (6, 0));
+        assert_eq!(reached.found_cost(), Some(10));
+
+        let path = reached.path(&(6, 0)).unwrap();
+        assert_eq!((path.len(), path[0], path[10]), (11, (0, 0), (6, 0)));
+        assert!(path.windows(2).all(|w| open(&w[0]).contains(&w[1])));
+
+        let all = bfs((0, 0), open, |_| false);
+        assert_eq!(all.found(), None);
+        assert_eq!(
+            all.costs().len(),
+            maze.iter().filter(|(_, open)| **open).count()
+        );
+
+        let multi = bfs_multi([(0, 0), (6, 4)], open, |coord| *coord == (6, 0));
+        assert_eq!(multi.found_cost(), Some(6));
+        assert_eq!(multi.path(&(6, 0)).unwrap()[0], (6, 4));
+    }
+
+    #[test]
+    fn test_weighted() {
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('c', 10), ('d', 15)],
+            'c' => vec![('d', 11), ('f', 2)],
+            'd' => vec![('e', 6)],
+            'f' => vec![('e', 9)],
+            _ => vec![],
+        };
+
+        let reached = dijkstra('a', edges, |node| *node == 'e');
+        assert_eq!(reached.found_cost(), Some(20));
+        assert_eq!(reached.path(&'e').unwrap(), ['a', 'c', 'f', 'e']);
+        assert_eq!(dijkstra('e', edges, |node| *node == 'a').found(), None);
+
+        let maze = maze();
+        let open = |coord: &Coord| -> Vec<(Coord, usize)> {
+            maze.neighbors4(*coord)
+                .filter(|next| maze[*next])
+                .map(|next| (next, 1))
+                .collect()
+        };
+        let manhattan = |(x, y): &Coord| 6 - x + y;
+
+        let reached = astar((0, 0), open, manhattan, |coord| *coord == (6, 0));
+        assert_eq!(reached.found_cost(), Some(10));
+        assert_eq!(reached.path(&(6, 0)).unwrap().len(), 11);
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+
+/// Error of a part which has no solution yet, it is reported as missing
+/// rather than as a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotImplemented;
+
+impl Display for NotImplemented {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+impl std::error::Error for NotImplemented {}
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type A: Display;
+    type B: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_a(input: &Self::Input) -> Result<Self::A>;
+    fn part_b(input: &Self::Input) -> Result<Self::B>;
+
+    /// Overrides a puzzle parameter which isn't part of the input, such as
+    /// the smaller bounds of an example.
+    fn configure(_input: &mut Self::Input, name: &str, _value: &str) -> Result<()> {
+        bail!("Day {:02} has no parameter {}", Self::DAY, name)
+    }
+}
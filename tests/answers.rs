@@ -0,0 +1,91 @@
+use std::path::{Path, PathBuf};
+
+use advent2022::{
+    answers::Answers,
+    registry::{self, Day, Part},
+};
+
+fn dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(name)
+}
+
+/// Solves `day` with `input` and the parameters in `answers`, and describes
+/// every part which differs from `answers`. Parts without a recorded answer
+/// are not checked.
+fn check(day: &Day, input: &str, answers: &Answers) -> Vec<String> {
+    let parts: Vec<_> = Part::all()
+        .into_iter()
+        .filter(|part| answers.get(day.day, *part).is_some())
+        .collect();
+
+    let solved = match day.solve_with(input, &parts, answers.params(day.day)) {
+        Ok(solved) => solved,
+        Err(err) => return vec![format!("day {:02}: {}", day.day, err)],
+    };
+
+    solved
+        .into_iter()
+        .filter_map(|answer| {
+            let expected = answers.get(day.day, answer.part)?;
+
+            match answer.value {
+                Ok(value) if value == expected => None,
+                Ok(value) => Some(format!(
+                    "day {:02} part {}: expected {}, got {}",
+                    day.day, answer.part, expected, value
+                )),
+                Err(err) => Some(format!("day {:02} part {}: {}", day.day, answer.part, err)),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn examples() {
+    let examples = dir("examples");
+    let answers = Answers::read(&examples.join("answers.toml")).unwrap();
+
+    let failures: Vec<_> = registry::DAYS
+        .iter()
+        .flat_map(|day| {
+            let path = examples.join(format!("day{:02}.txt", day.day));
+            let input = std::fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("can't read {}: {}", path.display(), err));
+
+            assert!(
+                Part::all()
+                    .iter()
+                    .any(|part| answers.get(day.day, *part).is_some()),
+                "day {:02} has no example answers",
+                day.day
+            );
+
+            check(day, &input, &answers)
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Inputs are personal and may be missing, only the ones present are checked.
+#[test]
+fn inputs() {
+    let inputs = dir("input");
+    let answers = match Answers::read(&inputs.join("answers.toml")) {
+        Ok(answers) => answers,
+        Err(_) => return,
+    };
+
+    let failures: Vec<_> = registry::DAYS
+        .iter()
+        .flat_map(|day| {
+            let path = inputs.join(format!("day{:02}.txt", day.day));
+            match std::fs::read_to_string(path) {
+                Ok(input) => check(day, &input, &answers),
+                Err(_) => vec![],
+            }
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}